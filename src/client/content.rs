@@ -0,0 +1,102 @@
+use std::{fs, path::PathBuf};
+use solana_program::hash::{hash, Hash};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ContentError {
+    #[error("Could not fetch {0}: {1}")]
+    Fetch(String, String),
+    #[error("Content at {uri} hashes to {actual}, expected {expected}")]
+    HashMismatch { uri: String, expected: Hash, actual: Hash },
+}
+
+// Where off-chain intro and reply content is fetched from
+pub trait ContentStore {
+    fn fetch(&self, uri: &str) -> Result<Vec<u8>, ContentError>;
+}
+
+// Resolves `file://` URIs and bare paths relative to `root`, used for local tooling and tests
+pub struct FileSystemStore {
+    pub root: PathBuf,
+}
+
+impl FileSystemStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl ContentStore for FileSystemStore {
+    fn fetch(&self, uri: &str) -> Result<Vec<u8>, ContentError> {
+        let path = uri.strip_prefix("file://").unwrap_or(uri);
+        fs::read(self.root.join(path.trim_start_matches('/')))
+            .map_err(|e| ContentError::Fetch(uri.to_string(), e.to_string()))
+    }
+}
+
+// Hash committed on-chain by AddIntroOffchain and AddReplyOffchain
+pub fn content_hash(content: &[u8]) -> [u8; 32] {
+    hash(content).to_bytes()
+}
+
+// Fetch content and check it against the on-chain commitment, returning the verified bytes
+pub fn verify_content<S: ContentStore>(
+    store: &S,
+    uri: &str,
+    expected_hash: &[u8; 32]
+) -> Result<Vec<u8>, ContentError> {
+    let content = store.fetch(uri)?;
+    let actual = hash(&content);
+    if actual.to_bytes() != *expected_hash {
+        return Err(ContentError::HashMismatch {
+            uri: uri.to_string(),
+            expected: Hash::new_from_array(*expected_hash),
+            actual,
+        });
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store_with(path: &str, content: &[u8]) -> (tempfile::TempDir, FileSystemStore) {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(path), content).unwrap();
+        let store = FileSystemStore::new(dir.path());
+        (dir, store)
+    }
+
+    #[test]
+    fn verifies_matching_content() {
+        let (_dir, store) = store_with("intro.md", b"Hello world");
+        let expected = content_hash(b"Hello world");
+        assert_eq!(verify_content(&store, "file:///intro.md", &expected).unwrap(), b"Hello world");
+        assert_eq!(verify_content(&store, "intro.md", &expected).unwrap(), b"Hello world");
+    }
+
+    #[test]
+    fn rejects_hash_mismatch() {
+        let (_dir, store) = store_with("intro.md", b"Hello world, edited");
+        let expected = content_hash(b"Hello world");
+        match verify_content(&store, "file:///intro.md", &expected) {
+            Err(ContentError::HashMismatch { uri, expected: committed, actual }) => {
+                assert_eq!(uri, "file:///intro.md");
+                assert_eq!(committed.to_bytes(), expected);
+                assert_eq!(actual.to_bytes(), content_hash(b"Hello world, edited"));
+            },
+            other => panic!("expected a hash mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let (_dir, store) = store_with("intro.md", b"Hello world");
+        let expected = content_hash(b"Hello world");
+        match verify_content(&store, "file:///missing.md", &expected) {
+            Err(ContentError::Fetch(uri, _)) => assert_eq!(uri, "file:///missing.md"),
+            other => panic!("expected a fetch error, got {other:?}"),
+        }
+    }
+}
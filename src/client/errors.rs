@@ -0,0 +1,81 @@
+use solana_program::{
+    instruction::InstructionError,
+    program_error::ProgramError,
+};
+use num_traits::FromPrimitive;
+use crate::error::IntroError;
+
+// Map a custom error code back to the program error it came from
+pub fn intro_error_from_code(code: u32) -> Option<IntroError> {
+    IntroError::from_u32(code)
+}
+
+// Recover the typed error from a ProgramError::Custom returned by the program
+pub fn intro_error_from_program_error(error: &ProgramError) -> Option<IntroError> {
+    match error {
+        ProgramError::Custom(code) => intro_error_from_code(*code),
+        _ => None,
+    }
+}
+
+// Recover the typed error from the InstructionError of a failed transaction
+pub fn intro_error_from_instruction_error(error: &InstructionError) -> Option<IntroError> {
+    match error {
+        InstructionError::Custom(code) => intro_error_from_code(*code),
+        _ => None,
+    }
+}
+
+// Human-readable message for a failed instruction, falling back to the runtime error
+pub fn describe_instruction_error(error: &InstructionError) -> String {
+    match intro_error_from_instruction_error(error) {
+        Some(intro_error) => format!("IntroError {}: {}", intro_error as u32, intro_error),
+        None => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Highest code in use, update when appending a variant
+    const LAST_CODE: u32 = IntroError::ProposalOutOfBounds as u32;
+
+    #[test]
+    fn every_code_round_trips() {
+        for code in 0..=LAST_CODE {
+            let intro_error = intro_error_from_code(code).unwrap_or_else(|| panic!("code {code} has no IntroError"));
+            assert_eq!(intro_error as u32, code);
+            assert_eq!(intro_error_from_program_error(&intro_error.into()), Some(intro_error));
+            assert_eq!(intro_error_from_instruction_error(&InstructionError::Custom(code)), Some(intro_error));
+        }
+    }
+
+    #[test]
+    fn unknown_codes_are_not_intro_errors() {
+        for code in [1_000, u32::MAX] {
+            assert_eq!(intro_error_from_code(code), None);
+            assert_eq!(intro_error_from_program_error(&ProgramError::Custom(code)), None);
+            assert_eq!(intro_error_from_instruction_error(&InstructionError::Custom(code)), None);
+            assert_eq!(describe_instruction_error(&InstructionError::Custom(code)), InstructionError::Custom(code).to_string());
+        }
+    }
+
+    #[test]
+    fn non_custom_errors_are_not_intro_errors() {
+        assert_eq!(intro_error_from_program_error(&ProgramError::InvalidArgument), None);
+        assert_eq!(intro_error_from_program_error(&ProgramError::MissingRequiredSignature), None);
+        assert_eq!(intro_error_from_instruction_error(&InstructionError::InvalidArgument), None);
+        assert_eq!(intro_error_from_instruction_error(&InstructionError::MissingRequiredSignature), None);
+        assert_eq!(
+            describe_instruction_error(&InstructionError::MissingRequiredSignature),
+            InstructionError::MissingRequiredSignature.to_string()
+        );
+    }
+
+    #[test]
+    fn describes_intro_errors() {
+        let error = InstructionError::Custom(IntroError::ContentTooLong as u32);
+        assert_eq!(describe_instruction_error(&error), "IntroError 19: Text exceeds the field length limit");
+    }
+}
@@ -0,0 +1,4 @@
+// Off-chain helpers for programs and tools talking to this program
+pub mod errors;
+pub mod content;
+pub mod reply_tree;
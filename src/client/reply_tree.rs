@@ -0,0 +1,134 @@
+use solana_program::{hash::hash, pubkey::Pubkey};
+use crate::merkle::{self, REPLY_TREE_CAPACITY, REPLY_TREE_DEPTH};
+
+// Off-chain mirror of an intro's ReplyTree, rebuilt from AddCompressedReply logs in leaf order
+pub struct ReplyTreeBuilder {
+    intro: Pubkey,
+    leaves: Vec<[u8; 32]>,
+}
+
+impl ReplyTreeBuilder {
+    pub fn new(intro: Pubkey) -> Self {
+        Self { intro, leaves: Vec::new() }
+    }
+
+    // `count` is the reply number logged by the program, not the leaf index
+    pub fn push_reply(&mut self, replier: &Pubkey, reply: &str, count: u64) -> Option<u64> {
+        let reply_hash = hash(reply.as_bytes()).to_bytes();
+        self.push_leaf(merkle::reply_leaf(&self.intro, replier, &reply_hash, count))
+    }
+
+    pub fn push_leaf(&mut self, leaf: [u8; 32]) -> Option<u64> {
+        if self.leaves.len() as u64 >= REPLY_TREE_CAPACITY {
+            return None;
+        }
+        self.leaves.push(leaf);
+        Some(self.leaves.len() as u64 - 1)
+    }
+
+    pub fn leaf_count(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    // Should equal ReplyTree::root once every logged reply has been pushed
+    pub fn root(&self) -> [u8; 32] {
+        let (root, _) = self.walk(None);
+        root
+    }
+
+    // Sibling path from the leaf at `index` up to the root
+    pub fn proof(&self, index: u64) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+        let (_, proof) = self.walk(Some(index as usize));
+        Some(proof)
+    }
+
+    // Hash level by level, padding each level with the empty-subtree hash
+    fn walk(&self, index: Option<usize>) -> ([u8; 32], Vec<[u8; 32]>) {
+        let zeros = merkle::zero_hashes();
+        let mut level_nodes = self.leaves.clone();
+        let mut proof = Vec::with_capacity(REPLY_TREE_DEPTH);
+        let mut position = index.unwrap_or(0);
+
+        for zero in zeros.iter() {
+            if index.is_some() {
+                let sibling = position ^ 1;
+                proof.push(level_nodes.get(sibling).copied().unwrap_or(*zero));
+                position /= 2;
+            }
+
+            level_nodes = level_nodes
+                .chunks(2)
+                .map(|pair| merkle::hash_node(&pair[0], pair.get(1).unwrap_or(zero)))
+                .collect();
+        }
+
+        let root = level_nodes.first().copied().unwrap_or_else(merkle::empty_root);
+        (root, proof)
+    }
+}
+
+// Check that a reply is included under a root read from the ReplyTree account
+pub fn verify_reply(
+    root: &[u8; 32],
+    intro: &Pubkey,
+    replier: &Pubkey,
+    reply: &str,
+    count: u64,
+    index: u64,
+    proof: &[[u8; 32]]
+) -> bool {
+    let reply_hash = hash(reply.as_bytes()).to_bytes();
+    let leaf = merkle::reply_leaf(intro, replier, &reply_hash, count);
+    merkle::verify_proof(root, leaf, index, proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_root_matches_append_leaf() {
+        let intro = Pubkey::new_unique();
+        let mut builder = ReplyTreeBuilder::new(intro);
+        let mut filled_subtrees = [[0u8; 32]; REPLY_TREE_DEPTH];
+        assert_eq!(builder.root(), merkle::empty_root());
+
+        for count in 1..=40u64 {
+            let replier = Pubkey::new_unique();
+            let reply = format!("reply {count}");
+            let index = builder.push_reply(&replier, &reply, count).unwrap();
+
+            let reply_hash = hash(reply.as_bytes()).to_bytes();
+            let root = merkle::append_leaf(&mut filled_subtrees, index, merkle::reply_leaf(&intro, &replier, &reply_hash, count)).unwrap();
+            assert_eq!(builder.root(), root, "root after {count} replies");
+        }
+    }
+
+    #[test]
+    fn generated_proofs_verify() {
+        let intro = Pubkey::new_unique();
+        let mut builder = ReplyTreeBuilder::new(intro);
+        let repliers: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        for (index, replier) in repliers.iter().enumerate() {
+            builder.push_reply(replier, &format!("reply {index}"), index as u64 + 1);
+        }
+        let root = builder.root();
+
+        for (index, replier) in repliers.iter().enumerate() {
+            let index = index as u64;
+            let reply = format!("reply {index}");
+            let proof = builder.proof(index).unwrap();
+            assert!(verify_reply(&root, &intro, replier, &reply, index + 1, index, &proof));
+
+            // Any change to the reply, its author, count or position breaks the proof
+            assert!(!verify_reply(&root, &intro, replier, "edited", index + 1, index, &proof));
+            assert!(!verify_reply(&root, &intro, &Pubkey::new_unique(), &reply, index + 1, index, &proof));
+            assert!(!verify_reply(&root, &intro, replier, &reply, index + 2, index, &proof));
+            assert!(!verify_reply(&root, &intro, replier, &reply, index + 1, index ^ 1, &proof));
+        }
+        assert_eq!(builder.proof(repliers.len() as u64), None);
+    }
+}
@@ -2,9 +2,11 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult,
     pubkey::Pubkey,
     msg,
-    entrypoint
+    entrypoint,
+    program_error::PrintProgramError
 };
 use crate::processor;
+use crate::error::IntroError;
 
 // Declare entrypoint
 entrypoint!(process_instruction);
@@ -20,7 +22,11 @@ fn process_instruction(
         accounts.len(),
         instruction_data
     );
-    processor::process_instruction(program_id, accounts, instruction_data)?;
+    if let Err(error) = processor::process_instruction(program_id, accounts, instruction_data) {
+        // Log the readable message for custom program errors
+        error.print::<IntroError>();
+        return Err(error);
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -1,25 +1,119 @@
 // inside error.rs
-use solana_program::program_error::ProgramError;
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use num_derive::FromPrimitive;
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+// Codes are returned to clients as ProgramError::Custom(code)
+// Append new variants at the end, never renumber or reuse an existing code
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error, FromPrimitive)]
+#[repr(u32)]
 pub enum IntroError{
-    // Error 0
     #[error("Account not initialized yet")]
-    UninitializedAccount,
-    // Error 1
+    UninitializedAccount = 0,
     #[error("PDA derived does not equal PDA passed in")]
-    InvalidPDA,
-    // Error 2
+    InvalidPDA = 1,
     #[error("Incorrect account entered")]
-    IncorrectAccountError,
-    // Error 3
+    IncorrectAccountError = 2,
     #[error("Input data exceeds max length")]
-    InvalidDataLength,
+    InvalidDataLength = 3,
+    #[error("Reply bond challenge window is still open")]
+    ChallengeWindowOpen = 4,
+    #[error("Reply bond challenge window has closed")]
+    ChallengeWindowClosed = 5,
+    #[error("Cannot follow yourself")]
+    SelfFollow = 6,
+    #[error("Community account does not match the intro community")]
+    CommunityMismatch = 7,
+    #[error("Account must be writable")]
+    AccountNotWritable = 8,
+    #[error("Program account does not match the expected program id")]
+    IncorrectProgramId = 9,
+    #[error("Account is not an intro")]
+    NotAnIntro = 10,
+    #[error("Intro account is not at the PDA derived from its introducer and index")]
+    IntroAddressMismatch = 11,
+    #[error("Reply counter does not belong to the intro")]
+    ReplyCounterMismatch = 12,
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow = 13,
+    #[error("Instruction data is malformed or has an unknown variant")]
+    InvalidInstruction = 14,
+    #[error("Signer is not allowed to perform this action")]
+    Unauthorized = 15,
+    #[error("Reply bond does not match the intro or replier passed in")]
+    BondMismatch = 16,
+    #[error("Community reward multiplier is out of range")]
+    InvalidRewardMultiplier = 17,
+    #[error("Text is empty or only whitespace")]
+    EmptyContent = 18,
+    #[error("Text exceeds the field length limit")]
+    ContentTooLong = 19,
+    #[error("Text contains control characters")]
+    ControlCharacter = 20,
+    #[error("Text contains invisible or bidirectional formatting characters")]
+    InvisibleCharacter = 21,
+    #[error("Text is not in Unicode NFC form")]
+    NotNormalized = 22,
+    #[error("Compressed reply tree is full")]
+    ReplyTreeFull = 23,
+    #[error("Number of accounts does not match the number of batched items")]
+    BatchSizeMismatch = 24,
+    #[error("Amount exceeds the staked balance")]
+    InsufficientStake = 25,
+    #[error("Unstaked tokens are still unbonding")]
+    StakeUnbonding = 26,
+    #[error("Bounty has not expired yet")]
+    BountyNotExpired = 27,
+    #[error("Introducer cannot award a bounty to their own reply")]
+    SelfAward = 28,
+    #[error("Cannot refer yourself")]
+    SelfReferral = 29,
+    #[error("Referrals only apply to a wallet's first intro")]
+    NotFirstIntro = 30,
+    #[error("Mint would exceed the reward token max supply")]
+    MaxSupplyExceeded = 31,
+    #[error("Program no longer holds the reward token mint authority")]
+    MintAuthorityUnavailable = 32,
+    #[error("Program does not hold the reward token freeze authority")]
+    FreezeAuthorityUnavailable = 33,
+    #[error("Proposal voting has ended")]
+    VotingClosed = 34,
+    #[error("Proposal voting is still open")]
+    VotingOpen = 35,
+    #[error("Proposal did not pass")]
+    ProposalNotPassed = 36,
+    #[error("Proposal was already executed")]
+    ProposalExecuted = 37,
+    #[error("Vesting account has no free tranche")]
+    VestingTranchesFull = 38,
+    #[error("Amount must be greater than zero")]
+    ZeroAmount = 39,
+    #[error("Expiry slot is not in the future")]
+    ExpiryInPast = 40,
+    #[error("Max supply is below the tokens already minted")]
+    MaxSupplyBelowMinted = 41,
+    #[error("Proposal action is outside the governed bounds")]
+    ProposalOutOfBounds = 42,
 }
 
 impl From<IntroError> for ProgramError {
     fn from(e: IntroError) -> Self {
         ProgramError::Custom(e as u32)
     }
-}
\ No newline at end of file
+}
+
+impl<T> DecodeError<T> for IntroError {
+    fn type_of() -> &'static str {
+        "IntroError"
+    }
+}
+
+impl PrintProgramError for IntroError {
+    fn print<E>(&self) {
+        msg!("IntroError {}: {}", *self as u32, self);
+    }
+}
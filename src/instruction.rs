@@ -1,25 +1,132 @@
 // Define and deserialize instructions
 
 use borsh::BorshDeserialize;
-use solana_program::program_error::ProgramError;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use crate::error::IntroError;
+use crate::state::ProposalAction;
 
 pub enum IntroInstruction {
     AddIntro {
         name: String,
         message: String,
+        community: Option<Pubkey>,
+        referrer: Option<Pubkey>,
     },
     UpdateIntro {
+        index: u64,
         name: String,
         message: String,
     },
     AddReply {
         reply: String
     },
-    InitializeMint
+    InitializeMint,
+    ReleaseReplyBond,
+    FlagReply,
+    CreateProfile {
+        display_name: String,
+        avatar_uri: String,
+        bio: String,
+    },
+    UpdateProfile {
+        display_name: String,
+        avatar_uri: String,
+        bio: String,
+    },
+    Follow,
+    Unfollow,
+    MigrateIntro,
+    CreateCommunity {
+        name: String,
+        description: String,
+        moderators: Vec<Pubkey>,
+        reward_multiplier: u16,
+    },
+    // Same as AddIntro, but the message lives off-chain and only its URI and hash are stored
+    AddIntroOffchain {
+        name: String,
+        content_uri: String,
+        content_hash: [u8; 32],
+        community: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+    },
+    // Same as AddReply, but the reply lives off-chain and only its URI and hash are stored
+    AddReplyOffchain {
+        content_uri: String,
+        content_hash: [u8; 32],
+    },
+    CreateReplyTree,
+    // Reply stored as a leaf in the intro's reply tree rather than its own account
+    AddCompressedReply {
+        reply: String
+    },
+    // Admin-only bulk import of replies by one author, who also signs, followed by one Reply PDA per reply
+    AddReplies {
+        replies: Vec<String>
+    },
+    ClaimVested,
+    ClawBackVesting,
+    Stake {
+        amount: u64
+    },
+    // Starts unbonding, the tokens are returned by WithdrawStake once it ends
+    Unstake {
+        amount: u64
+    },
+    WithdrawStake,
+    // Burns reward tokens to raise the intro's boost score
+    BoostIntro {
+        amount: u64
+    },
+    CreateBounty {
+        amount: u64,
+        expiry_slot: u64,
+    },
+    // Pays the bounty to the author of the Reply PDA passed in
+    AwardBounty,
+    // Returns the bounty to the introducer, only after its expiry slot
+    CancelBounty,
+    // Admin-only, sets tunable Config values
+    UpdateConfig {
+        referral_reward: u64,
+        max_supply: u64,
+    },
+    // Admin-only mint of `amounts[i]` to recipient i, followed by a (wallet, ATA) pair per recipient
+    Airdrop {
+        amounts: Vec<u64>
+    },
+    // Admin-only, moves the freeze authority off the token_auth PDA, None disables freezing
+    SetFreezeAuthority {
+        new_authority: Option<Pubkey>
+    },
+    // Admin-only, hands the mint authority to a multisig or governance program
+    TransferMintAuthority {
+        new_authority: Pubkey
+    },
+    // Admin-only, disables minting permanently
+    RevokeMintAuthority,
+    CreateProposal {
+        action: ProposalAction
+    },
+    // Votes with the voter's staked balance
+    CastVote {
+        approve: bool
+    },
+    // Applies a passed proposal once voting has ended, callable by anyone
+    ExecuteProposal
 }
 
 #[derive(BorshDeserialize)]
 struct IntroPayload {
+    name: String,
+    message: String,
+    community: Option<Pubkey>,
+    referrer: Option<Pubkey>
+}
+
+#[derive(BorshDeserialize)]
+struct UpdateIntroPayload {
+    index: u64,
     name: String,
     message: String
 }
@@ -29,32 +136,405 @@ struct ReplyPayload {
     reply: String
 }
 
+#[derive(BorshDeserialize)]
+struct RepliesPayload {
+    replies: Vec<String>
+}
+
+#[derive(BorshDeserialize)]
+struct AmountPayload {
+    amount: u64
+}
+
+#[derive(BorshDeserialize)]
+struct BountyPayload {
+    amount: u64,
+    expiry_slot: u64
+}
+
+#[derive(BorshDeserialize)]
+struct ConfigPayload {
+    referral_reward: u64,
+    max_supply: u64
+}
+
+#[derive(BorshDeserialize)]
+struct FreezeAuthorityPayload {
+    new_authority: Option<Pubkey>
+}
+
+#[derive(BorshDeserialize)]
+struct MintAuthorityPayload {
+    new_authority: Pubkey
+}
+
+#[derive(BorshDeserialize)]
+struct ProposalPayload {
+    action: ProposalAction
+}
+
+#[derive(BorshDeserialize)]
+struct VotePayload {
+    approve: bool
+}
+
+#[derive(BorshDeserialize)]
+struct AirdropPayload {
+    amounts: Vec<u64>
+}
+
+#[derive(BorshDeserialize)]
+struct CommunityPayload {
+    name: String,
+    description: String,
+    moderators: Vec<Pubkey>,
+    reward_multiplier: u16
+}
+
+#[derive(BorshDeserialize)]
+struct OffchainIntroPayload {
+    name: String,
+    content_uri: String,
+    content_hash: [u8; 32],
+    community: Option<Pubkey>,
+    referrer: Option<Pubkey>
+}
+
+#[derive(BorshDeserialize)]
+struct OffchainReplyPayload {
+    content_uri: String,
+    content_hash: [u8; 32]
+}
+
+#[derive(BorshDeserialize)]
+struct ProfilePayload {
+    display_name: String,
+    avatar_uri: String,
+    bio: String
+}
+
+// Every malformed payload, including trailing bytes after it, is an InvalidInstruction
+fn decode<T: BorshDeserialize>(rest: &[u8]) -> Result<T, ProgramError> {
+    T::try_from_slice(rest).map_err(|_| IntroError::InvalidInstruction.into())
+}
+
 impl IntroInstruction {
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&variant, rest) = input.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+        let (&variant, rest) = input.split_first().ok_or(IntroError::InvalidInstruction)?;
         Ok(match variant {
             0 => {
-                let payload = IntroPayload::try_from_slice(rest).unwrap();
+                let payload: IntroPayload = decode(rest)?;
                 Self::AddIntro {
                     name: payload.name,
-                    message: payload.message
+                    message: payload.message,
+                    community: payload.community,
+                    referrer: payload.referrer
                 }
             },
             1 => {
-                let payload = IntroPayload::try_from_slice(rest).unwrap();
+                let payload: UpdateIntroPayload = decode(rest)?;
                 Self::UpdateIntro {
+                    index: payload.index,
                     name: payload.name,
                     message: payload.message
                 }
             },
             2 => {
-                let payload = ReplyPayload::try_from_slice(rest).unwrap();
+                let payload: ReplyPayload = decode(rest)?;
                 Self::AddReply {
                     reply: payload.reply                
                 }
             },
-            3 => Self::InitializeMint,
-            _ => return Err(ProgramError::InvalidInstructionData)
+            3 => {
+                decode::<()>(rest)?;
+                Self::InitializeMint
+            },
+            4 => {
+                decode::<()>(rest)?;
+                Self::ReleaseReplyBond
+            },
+            5 => {
+                decode::<()>(rest)?;
+                Self::FlagReply
+            },
+            6 => {
+                let payload: ProfilePayload = decode(rest)?;
+                Self::CreateProfile {
+                    display_name: payload.display_name,
+                    avatar_uri: payload.avatar_uri,
+                    bio: payload.bio
+                }
+            },
+            7 => {
+                let payload: ProfilePayload = decode(rest)?;
+                Self::UpdateProfile {
+                    display_name: payload.display_name,
+                    avatar_uri: payload.avatar_uri,
+                    bio: payload.bio
+                }
+            },
+            8 => {
+                decode::<()>(rest)?;
+                Self::Follow
+            },
+            9 => {
+                decode::<()>(rest)?;
+                Self::Unfollow
+            },
+            10 => {
+                decode::<()>(rest)?;
+                Self::MigrateIntro
+            },
+            11 => {
+                let payload: CommunityPayload = decode(rest)?;
+                Self::CreateCommunity {
+                    name: payload.name,
+                    description: payload.description,
+                    moderators: payload.moderators,
+                    reward_multiplier: payload.reward_multiplier
+                }
+            },
+            12 => {
+                let payload: OffchainIntroPayload = decode(rest)?;
+                Self::AddIntroOffchain {
+                    name: payload.name,
+                    content_uri: payload.content_uri,
+                    content_hash: payload.content_hash,
+                    community: payload.community,
+                    referrer: payload.referrer
+                }
+            },
+            13 => {
+                let payload: OffchainReplyPayload = decode(rest)?;
+                Self::AddReplyOffchain {
+                    content_uri: payload.content_uri,
+                    content_hash: payload.content_hash
+                }
+            },
+            14 => {
+                decode::<()>(rest)?;
+                Self::CreateReplyTree
+            },
+            15 => {
+                let payload: ReplyPayload = decode(rest)?;
+                Self::AddCompressedReply {
+                    reply: payload.reply
+                }
+            },
+            16 => {
+                let payload: RepliesPayload = decode(rest)?;
+                Self::AddReplies {
+                    replies: payload.replies
+                }
+            },
+            17 => {
+                decode::<()>(rest)?;
+                Self::ClaimVested
+            },
+            18 => {
+                decode::<()>(rest)?;
+                Self::ClawBackVesting
+            },
+            19 => {
+                let payload: AmountPayload = decode(rest)?;
+                Self::Stake {
+                    amount: payload.amount
+                }
+            },
+            20 => {
+                let payload: AmountPayload = decode(rest)?;
+                Self::Unstake {
+                    amount: payload.amount
+                }
+            },
+            21 => {
+                decode::<()>(rest)?;
+                Self::WithdrawStake
+            },
+            22 => {
+                let payload: AmountPayload = decode(rest)?;
+                Self::BoostIntro {
+                    amount: payload.amount
+                }
+            },
+            23 => {
+                let payload: BountyPayload = decode(rest)?;
+                Self::CreateBounty {
+                    amount: payload.amount,
+                    expiry_slot: payload.expiry_slot
+                }
+            },
+            24 => {
+                decode::<()>(rest)?;
+                Self::AwardBounty
+            },
+            25 => {
+                decode::<()>(rest)?;
+                Self::CancelBounty
+            },
+            26 => {
+                let payload: ConfigPayload = decode(rest)?;
+                Self::UpdateConfig {
+                    referral_reward: payload.referral_reward,
+                    max_supply: payload.max_supply
+                }
+            },
+            27 => {
+                let payload: AirdropPayload = decode(rest)?;
+                Self::Airdrop {
+                    amounts: payload.amounts
+                }
+            },
+            28 => {
+                let payload: FreezeAuthorityPayload = decode(rest)?;
+                Self::SetFreezeAuthority {
+                    new_authority: payload.new_authority
+                }
+            },
+            29 => {
+                let payload: MintAuthorityPayload = decode(rest)?;
+                Self::TransferMintAuthority {
+                    new_authority: payload.new_authority
+                }
+            },
+            30 => {
+                decode::<()>(rest)?;
+                Self::RevokeMintAuthority
+            },
+            31 => {
+                let payload: ProposalPayload = decode(rest)?;
+                Self::CreateProposal {
+                    action: payload.action
+                }
+            },
+            32 => {
+                let payload: VotePayload = decode(rest)?;
+                Self::CastVote {
+                    approve: payload.approve
+                }
+            },
+            33 => {
+                decode::<()>(rest)?;
+                Self::ExecuteProposal
+            },
+            _ => return Err(IntroError::InvalidInstruction.into())
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+
+    fn encode<T: BorshSerialize>(variant: u8, payload: T) -> Vec<u8> {
+        let mut data = vec![variant];
+        payload.serialize(&mut data).unwrap();
+        data
+    }
+
+    // One well-formed encoding of every instruction
+    fn valid_instructions() -> Vec<Vec<u8>> {
+        let key = Pubkey::new_unique();
+        let text = || "hello".to_string();
+        vec![
+            encode(0, (text(), text(), Some(key), Some(key))),
+            encode(1, (7u64, text(), text())),
+            encode(2, text()),
+            vec![3],
+            vec![4],
+            vec![5],
+            encode(6, (text(), text(), text())),
+            encode(7, (text(), text(), text())),
+            vec![8],
+            vec![9],
+            vec![10],
+            encode(11, (text(), text(), vec![key], 100u16)),
+            encode(12, (text(), text(), [7u8; 32], Some(key), None::<Pubkey>)),
+            encode(13, (text(), [7u8; 32])),
+            vec![14],
+            encode(15, text()),
+            encode(16, vec![text(), text()]),
+            vec![17],
+            vec![18],
+            encode(19, 5u64),
+            encode(20, 5u64),
+            vec![21],
+            encode(22, 5u64),
+            encode(23, (5u64, 9u64)),
+            vec![24],
+            vec![25],
+            encode(26, (5u64, 9u64)),
+            encode(27, vec![5u64, 9u64]),
+            encode(28, Some(key)),
+            encode(29, key),
+            vec![30],
+            encode(31, ProposalAction::SetMaxMessageBytes { max_bytes: 500 }),
+            encode(32, true),
+            vec![33],
+        ]
+    }
+
+    fn invalid() -> Option<ProgramError> {
+        Some(IntroError::InvalidInstruction.into())
+    }
+
+    #[test]
+    fn unpacks_every_variant() {
+        let instructions = valid_instructions();
+        for (variant, data) in instructions.iter().enumerate() {
+            assert_eq!(data[0] as usize, variant);
+            assert!(IntroInstruction::unpack(data).is_ok(), "variant {variant} did not unpack");
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_payloads() {
+        assert_eq!(IntroInstruction::unpack(&[]).err(), invalid());
+        for data in valid_instructions() {
+            for len in 1..data.len() {
+                assert_eq!(IntroInstruction::unpack(&data[..len]).err(), invalid(), "variant {} truncated to {len} bytes", data[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        for mut data in valid_instructions() {
+            data.push(0);
+            assert_eq!(IntroInstruction::unpack(&data).err(), invalid(), "variant {} with a trailing byte", data[0]);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_variants() {
+        for variant in valid_instructions().len() as u8..=u8::MAX {
+            assert_eq!(IntroInstruction::unpack(&[variant]).err(), invalid());
+        }
+    }
+
+    #[test]
+    fn garbage_never_panics() {
+        // Small xorshift so the sweep is deterministic
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        };
+        for variant in 0..=u8::MAX {
+            for len in 0..64 {
+                let mut data = vec![variant];
+                data.extend((0..len).map(|_| next_byte()));
+                if let Err(error) = IntroInstruction::unpack(&data) {
+                    assert_eq!(Some(error), invalid());
+                }
+            }
+            // Length prefixes far beyond the payload
+            let data = [variant, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+            if let Err(error) = IntroInstruction::unpack(&data) {
+                assert_eq!(Some(error), invalid());
+            }
+        }
+    }
+}
@@ -3,4 +3,9 @@ pub mod instruction;
 pub mod processor;
 pub mod state;
 pub mod error;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod pda;
+pub mod validation;
+pub mod merkle;
+#[cfg(not(target_os = "solana"))]
+pub mod client;
\ No newline at end of file
@@ -0,0 +1,119 @@
+// Append-only Merkle tree over compressed replies, shared by the program and off-chain proof tooling
+// Leaves and nodes are domain-separated sha256 so a node can never be passed off as a leaf
+
+use solana_program::{hash::hashv, pubkey::Pubkey};
+use crate::error::IntroError;
+
+// 2^14 compressed replies per intro
+pub const REPLY_TREE_DEPTH: usize = 14;
+pub const REPLY_TREE_CAPACITY: u64 = 1 << REPLY_TREE_DEPTH;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+pub fn reply_leaf(intro: &Pubkey, replier: &Pubkey, reply_hash: &[u8; 32], count: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, intro.as_ref(), replier.as_ref(), reply_hash, &count.to_le_bytes()]).to_bytes()
+}
+
+pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+// Root of an all-empty subtree at each level, level 0 being an empty leaf
+pub fn zero_hashes() -> [[u8; 32]; REPLY_TREE_DEPTH] {
+    let mut zeros = [[0u8; 32]; REPLY_TREE_DEPTH];
+    for level in 1..REPLY_TREE_DEPTH {
+        zeros[level] = hash_node(&zeros[level - 1], &zeros[level - 1]);
+    }
+    zeros
+}
+
+pub fn empty_root() -> [u8; 32] {
+    let zeros = zero_hashes();
+    hash_node(&zeros[REPLY_TREE_DEPTH - 1], &zeros[REPLY_TREE_DEPTH - 1])
+}
+
+// Append `leaf` at position `leaf_count`, keeping only the left-hand frontier, and return the new root
+pub fn append_leaf(
+    filled_subtrees: &mut [[u8; 32]; REPLY_TREE_DEPTH],
+    leaf_count: u64,
+    leaf: [u8; 32]
+) -> Result<[u8; 32], IntroError> {
+    if leaf_count >= REPLY_TREE_CAPACITY {
+        return Err(IntroError::ReplyTreeFull);
+    }
+
+    let zeros = zero_hashes();
+    let mut index = leaf_count;
+    let mut node = leaf;
+    for level in 0..REPLY_TREE_DEPTH {
+        if index & 1 == 0 {
+            filled_subtrees[level] = node;
+            node = hash_node(&node, &zeros[level]);
+        } else {
+            node = hash_node(&filled_subtrees[level], &node);
+        }
+        index /= 2;
+    }
+
+    Ok(node)
+}
+
+// Fold a leaf up through its sibling path and compare against `root`
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> bool {
+    if proof.len() != REPLY_TREE_DEPTH || index >= REPLY_TREE_CAPACITY {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut index = index;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            hash_node(&node, sibling)
+        } else {
+            hash_node(sibling, &node)
+        };
+        index /= 2;
+    }
+
+    node == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(i: u64) -> [u8; 32] {
+        reply_leaf(&Pubkey::new_from_array([1; 32]), &Pubkey::new_from_array([2; 32]), &[3; 32], i)
+    }
+
+    #[test]
+    fn empty_root_is_all_zero_leaves() {
+        let mut node = [0u8; 32];
+        for _ in 0..REPLY_TREE_DEPTH {
+            node = hash_node(&node, &node);
+        }
+        assert_eq!(empty_root(), node);
+    }
+
+    #[test]
+    fn first_leaf_proof() {
+        let mut filled_subtrees = [[0u8; 32]; REPLY_TREE_DEPTH];
+        let root = append_leaf(&mut filled_subtrees, 0, leaf(0)).unwrap();
+
+        // The only leaf's siblings are all empty subtrees
+        let proof = zero_hashes();
+        assert!(verify_proof(&root, leaf(0), 0, &proof));
+        assert!(!verify_proof(&root, leaf(1), 0, &proof));
+        assert!(!verify_proof(&root, leaf(0), 1, &proof));
+        assert!(!verify_proof(&root, leaf(0), 0, &proof[1..]));
+        assert!(!verify_proof(&root, leaf(0), REPLY_TREE_CAPACITY, &proof));
+    }
+
+    #[test]
+    fn rejects_leaves_past_capacity() {
+        let mut filled_subtrees = [[0u8; 32]; REPLY_TREE_DEPTH];
+        assert_eq!(append_leaf(&mut filled_subtrees, REPLY_TREE_CAPACITY, leaf(0)), Err(IntroError::ReplyTreeFull));
+        assert!(append_leaf(&mut filled_subtrees, REPLY_TREE_CAPACITY - 1, leaf(0)).is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+// Every PDA seed used by the program, in one place
+// Accounts store their bump so later instructions can re-derive with `create_program_address`
+// instead of paying for `find_program_address`
+
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    program_error::ProgramError,
+};
+use crate::error::IntroError;
+
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const TOKEN_MINT_SEED: &[u8] = b"token_mint";
+pub const TOKEN_AUTH_SEED: &[u8] = b"token_auth";
+pub const INTRO_COUNTER_SEED: &[u8] = b"intro_counter";
+pub const REPLY_COUNTER_SEED: &[u8] = b"reply";
+pub const BOND_SEED: &[u8] = b"bond";
+pub const PROFILE_SEED: &[u8] = b"profile";
+pub const COMMUNITY_SEED: &[u8] = b"community";
+pub const REPLY_TREE_SEED: &[u8] = b"reply_tree";
+pub const VESTING_SEED: &[u8] = b"vesting";
+pub const STAKE_SEED: &[u8] = b"stake";
+pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
+pub const BOUNTY_SEED: &[u8] = b"bounty";
+pub const REFERRAL_SEED: &[u8] = b"referral";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const VOTE_SEED: &[u8] = b"vote";
+
+pub fn config_seeds() -> [&'static [u8]; 1] {
+    [CONFIG_SEED]
+}
+
+pub fn token_mint_seeds() -> [&'static [u8]; 1] {
+    [TOKEN_MINT_SEED]
+}
+
+pub fn token_auth_seeds() -> [&'static [u8]; 1] {
+    [TOKEN_AUTH_SEED]
+}
+
+pub fn intro_counter_seeds(initializer: &Pubkey) -> [&[u8]; 2] {
+    [INTRO_COUNTER_SEED, initializer.as_ref()]
+}
+
+// Unscoped intros use an empty community seed, which derives the same address as [initializer, index]
+pub fn intro_seeds<'a>(community: &'a Option<Pubkey>, initializer: &'a Pubkey, index: &'a [u8; 8]) -> [&'a [u8]; 3] {
+    let community_seed: &[u8] = match community {
+        Some(community_key) => community_key.as_ref(),
+        None => &[],
+    };
+    [community_seed, initializer.as_ref(), index]
+}
+
+pub fn reply_counter_seeds(intro: &Pubkey) -> [&[u8]; 2] {
+    [intro.as_ref(), REPLY_COUNTER_SEED]
+}
+
+pub fn reply_tree_seeds(intro: &Pubkey) -> [&[u8]; 2] {
+    [intro.as_ref(), REPLY_TREE_SEED]
+}
+
+pub fn reply_seeds<'a>(intro: &'a Pubkey, count: &'a [u8; 8]) -> [&'a [u8]; 2] {
+    [intro.as_ref(), count]
+}
+
+pub fn bond_seeds(reply: &Pubkey) -> [&[u8]; 2] {
+    [BOND_SEED, reply.as_ref()]
+}
+
+pub fn profile_seeds(wallet: &Pubkey) -> [&[u8]; 2] {
+    [PROFILE_SEED, wallet.as_ref()]
+}
+
+pub fn follow_seeds<'a>(follower: &'a Pubkey, followee: &'a Pubkey) -> [&'a [u8]; 2] {
+    [follower.as_ref(), followee.as_ref()]
+}
+
+pub fn vesting_seeds(wallet: &Pubkey) -> [&[u8]; 2] {
+    [VESTING_SEED, wallet.as_ref()]
+}
+
+pub fn stake_seeds(wallet: &Pubkey) -> [&[u8]; 2] {
+    [STAKE_SEED, wallet.as_ref()]
+}
+
+// Owner of the vault ATA holding every staked token
+pub fn stake_vault_seeds() -> [&'static [u8]; 1] {
+    [STAKE_VAULT_SEED]
+}
+
+// One bounty per intro, the PDA also owns the escrow ATA
+pub fn bounty_seeds(intro: &Pubkey) -> [&[u8]; 2] {
+    [BOUNTY_SEED, intro.as_ref()]
+}
+
+pub fn referral_seeds(referee: &Pubkey) -> [&[u8]; 2] {
+    [REFERRAL_SEED, referee.as_ref()]
+}
+
+pub fn proposal_seeds(index: &[u8; 8]) -> [&[u8]; 2] {
+    [PROPOSAL_SEED, index]
+}
+
+pub fn vote_seeds<'a>(proposal: &'a Pubkey, voter: &'a Pubkey) -> [&'a [u8]; 3] {
+    [VOTE_SEED, proposal.as_ref(), voter.as_ref()]
+}
+
+pub fn community_seeds(name: &str) -> [&[u8]; 2] {
+    [COMMUNITY_SEED, name.as_bytes()]
+}
+
+// Single-intro scheme used before intros were index-seeded, only read by MigrateIntro
+pub fn legacy_intro_seeds(initializer: &Pubkey) -> [&[u8]; 1] {
+    [initializer.as_ref()]
+}
+
+pub fn legacy_reply_counter_seeds(initializer: &Pubkey) -> [&[u8]; 2] {
+    [initializer.as_ref(), REPLY_COUNTER_SEED]
+}
+
+// Re-derive `expected` from its seeds and stored bump
+pub fn check_pda(
+    seeds: &[&[u8]],
+    bump: u8,
+    program_id: &Pubkey,
+    expected: &Pubkey
+) -> Result<(), ProgramError> {
+    let bump_seed = [bump];
+    let mut seeds_with_bump = seeds.to_vec();
+    seeds_with_bump.push(&bump_seed);
+
+    match Pubkey::create_program_address(&seeds_with_bump, program_id) {
+        Ok(pda) if pda == *expected => Ok(()),
+        _ => {
+            msg!("Invalid seeds for PDA {}", expected);
+            Err(IntroError::InvalidPDA.into())
+        }
+    }
+}
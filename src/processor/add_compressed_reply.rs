@@ -0,0 +1,95 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    hash::hash,
+};
+use borsh::BorshSerialize;
+use crate::state::{IntroReplyCounterZc, ReplyTree};
+use crate::error::IntroError;
+use crate::merkle;
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+use crate::utils::load_intro::load_intro;
+use crate::utils::load_reply_counter::load_reply_counter;
+use crate::utils::validators::{check_signer, check_writable, check_owner, check_seeds};
+use crate::validation::{validate_text, REPLY_LIMITS};
+
+pub struct AddCompressedReplyAccounts<'a, 'info> {
+    pub replier: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_counter: &'a AccountInfo<'info>,
+    pub pda_tree: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AddCompressedReplyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            replier: next_account_info(account_info_iter)?,
+            pda_intro: next_account_info(account_info_iter)?,
+            pda_counter: next_account_info(account_info_iter)?,
+            pda_tree: next_account_info(account_info_iter)?,
+        };
+
+        check_signer(ctx.replier)?;
+
+        // Validate that program owns PDAs
+        check_owner(ctx.pda_counter, program_id)?;
+        check_writable(ctx.pda_counter)?;
+        check_owner(ctx.pda_tree, program_id)?;
+        check_writable(ctx.pda_tree)?;
+
+        Ok(ctx)
+    }
+}
+
+// Append a reply leaf to the intro's tree instead of creating an IntroReply account
+// The reply text only lives in the transaction logs, indexers rebuild the tree from them
+pub fn add_compressed_reply(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reply: String,
+) -> ProgramResult {
+    msg!("Adding compressed reply...");
+
+    let AddCompressedReplyAccounts { replier, pda_intro, pda_counter, pda_tree } = AddCompressedReplyAccounts::parse(program_id, accounts)?;
+
+    validate_text(&reply, &REPLY_LIMITS)?;
+
+    load_intro(program_id, pda_intro)?;
+
+    // Compressed and account replies share the intro's reply numbering
+    let counter = load_reply_counter(program_id, pda_counter, pda_intro.key)?;
+    let next_counter = counter.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+
+    let mut tree_data = decode_account::<ReplyTree>(pda_tree)?;
+
+    if !tree_data.is_initialized() || tree_data.discriminator != ReplyTree::DISCRIMINATOR {
+        msg!("Reply Tree PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    // Validate pda_tree is the reply tree of pda_intro
+    check_seeds(pda_tree, &pda::reply_tree_seeds(pda_intro.key), tree_data.bump, program_id)?;
+
+    let reply_hash = hash(reply.as_bytes()).to_bytes();
+    let leaf = merkle::reply_leaf(pda_intro.key, replier.key, &reply_hash, next_counter);
+    let leaf_index = tree_data.leaf_count;
+
+    tree_data.root = merkle::append_leaf(&mut tree_data.filled_subtrees, leaf_index, leaf)?;
+    tree_data.leaf_count = leaf_index.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+    tree_data.serialize(&mut &mut pda_tree.data.borrow_mut()[..])?;
+
+    IntroReplyCounterZc::load_mut(&mut pda_counter.data.borrow_mut())?.set_counter(next_counter);
+
+    msg!("Compressed reply {} from {} at leaf {}", next_counter, replier.key, leaf_index);
+    msg!("Reply: {}", reply);
+    msg!("Reply tree root: {:?}", tree_data.root);
+
+    Ok(())
+}
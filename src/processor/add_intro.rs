@@ -3,96 +3,227 @@ use solana_program::{
     pubkey::Pubkey,
     msg,
     account_info::{next_account_info, AccountInfo},
-    system_instruction,
     sysvar::{rent::Rent, Sysvar},
-    program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::IsInitialized,
-    borsh0_10::try_from_slice_unchecked,
-    native_token::LAMPORTS_PER_SOL
+    system_program::ID as SYSTEM_PROGRAM_ID
 };
-use spl_associated_token_account::{instruction::create_associated_token_account, get_associated_token_address};
-use spl_token::ID as TOKEN_PROGRAM_ID;
-use std::convert::TryInto;
 use borsh::BorshSerialize;
-use crate::state::{IntroAccountState, IntroReplyCounter};
+use crate::state::{Config, IntroAccountState, IntroReplyCounter, IntroReplyCounterZc, Referral};
 use crate::error::IntroError;
-use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::next_intro_index::next_intro_index;
+use crate::utils::load_community::load_community;
+use crate::utils::accrue_vesting::accrue_vesting;
+use crate::utils::load_stake_tier::load_stake_tier;
+use crate::utils::load_config::load_config;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+use crate::pda;
+use crate::validation::{validate_text, NAME_LIMITS, MESSAGE_LIMITS, CONTENT_URI_LIMITS};
+use crate::utils::decode_account::decode_account;
+
+// Accounts for recording a referral and vesting the referrer's reward, passed after the community account when a referrer is given
+pub struct ReferralAccounts<'a, 'info> {
+    pub referrer: &'a AccountInfo<'info>,
+    pub pda_referral: &'a AccountInfo<'info>,
+    pub pda_referrer_vesting: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ReferralAccounts<'a, 'info> {
+    pub fn parse<I: Iterator<Item = &'a AccountInfo<'info>>>(
+        account_info_iter: &mut I,
+        referrer_key: &Pubkey
+    ) -> Result<Self, ProgramError> {
+        let referrer = next_account_info(account_info_iter)?;
+        let pda_referral = next_account_info(account_info_iter)?;
+        let pda_referrer_vesting = next_account_info(account_info_iter)?;
+
+        if referrer.key != referrer_key {
+            msg!("Referrer account does not match the referrer in the instruction");
+            return Err(IntroError::IncorrectAccountError.into());
+        }
+        check_writable(pda_referral)?;
+
+        Ok(Self {
+            referrer,
+            pda_referral,
+            pda_referrer_vesting,
+        })
+    }
+}
+
+pub struct AddIntroAccounts<'a, 'info> {
+    pub initializer: &'a AccountInfo<'info>,
+    pub pda_intro_counter: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_counter: &'a AccountInfo<'info>,
+    pub pda_vesting: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config: Config,
+    // Community account for community-scoped intros, then the referral accounts when a referrer is given
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AddIntroAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let initializer = next_account_info(account_info_iter)?;
+        let pda_intro_counter = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_counter = next_account_info(account_info_iter)?;
+        let pda_vesting = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        // No msg.sender global variable available in Solana, and fee-payer is not available here either
+        // So convention is to send initiating address as the first provided account => Check this is signer
+        check_signer(initializer)?;
+        check_writable(initializer)?;
+
+        // Program accounts are invoked, so substituting a fake program must be rejected
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_writable(pda_intro_counter)?;
+        check_writable(pda_intro)?;
+        check_writable(pda_counter)?;
+
+        // Reward amount and message limits are governed through Config
+        let config = load_config(program_id, pda_config)?;
+
+        Ok(Self {
+            initializer,
+            pda_intro_counter,
+            pda_intro,
+            pda_counter,
+            pda_vesting,
+            pda_stake,
+            system_program,
+            config,
+            remaining_accounts: account_info_iter.as_slice(),
+        })
+    }
+}
 
 pub fn add_intro(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     name: String,
-    message: String
+    message: String,
+    community: Option<Pubkey>,
+    // Some when `message` is the URI of off-chain content with this hash
+    content_hash: Option<[u8; 32]>,
+    referrer: Option<Pubkey>
 ) -> ProgramResult {
     msg!("Adding introduction...");
     msg!("Name: {}", name);
     msg!("Message: {}", message);
     
-    // Get Account iterator
-    let account_info_iter = &mut accounts.iter();
-    
-    // Get accounts
-    let initializer = next_account_info(account_info_iter)?;
-    let pda_intro = next_account_info(account_info_iter)?;
-    let pda_counter = next_account_info(account_info_iter)?;
-    let token_mint = next_account_info(account_info_iter)?;
-    let mint_auth = next_account_info(account_info_iter)?;
-    let user_ata = next_account_info(account_info_iter)?;
-    let system_program = next_account_info(account_info_iter)?;
-    let token_program = next_account_info(account_info_iter)?;
-    let associated_token_program = next_account_info(account_info_iter)?;
-
-    // Check that `initializer` is signer
-    // No msg.sender global variable available in Solana, and fee-payer is not available here either
-    // So convention is to send initiating address as the first provided account => Check this is signer
-    if !initializer.is_signer {
-        msg!("Missing required signature");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let AddIntroAccounts {
+        initializer,
+        pda_intro_counter,
+        pda_intro,
+        pda_counter,
+        pda_vesting,
+        pda_stake,
+        system_program,
+        config,
+        remaining_accounts,
+    } = AddIntroAccounts::parse(program_id, accounts)?;
 
-    msg!("deriving mint authority");
-    let (mint_pda, _mint_bump) = Pubkey::find_program_address(&[b"token_mint"], program_id);
-    let (mint_auth_pda, mint_auth_bump) = Pubkey::find_program_address(&[b"token_auth"], program_id);
+    // Stakers get longer messages, the intro account grows by the extra bytes allowed
+    let tier = load_stake_tier(program_id, pda_stake, initializer.key)?;
 
-    if *token_mint.key != mint_pda {
-        msg!("Incorrect token mint");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
+    validate_text(&name, &NAME_LIMITS)?;
+    let message_limits = if content_hash.is_some() { CONTENT_URI_LIMITS } else { config.message_limits(MESSAGE_LIMITS).for_stake_tier(tier) };
+    validate_text(&message, &message_limits)?;
+    let extra_bytes = message_limits.max_bytes.saturating_sub(MESSAGE_LIMITS.max_bytes);
 
-    if *mint_auth.key != mint_auth_pda {
-        msg!("Mint passed in and mint derived do not match");
-        return Err(IntroError::InvalidPDA.into());
+    // Community-scoped intros pass the Community account first among the remaining accounts
+    let remaining_iter = &mut remaining_accounts.iter();
+    let mut reward_amount = config.intro_reward_amount();
+    if let Some(community_key) = community {
+        let community_account = next_account_info(remaining_iter)?;
+        let community_data = load_community(program_id, community_account, &community_key)?;
+        reward_amount = community_data.apply_reward_multiplier(reward_amount)?;
+        msg!("Posting in community {}", community_data.name);
     }
 
-    if *user_ata.key != get_associated_token_address(initializer.key, token_mint.key) {
-        msg!("Incorrect token mint");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
+    let rent = Rent::get()?;
 
-    if *token_program.key != TOKEN_PROGRAM_ID {
-        msg!("Incorrect token program");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
+    let referral = match referrer {
+        Some(referrer_key) => {
+            if referrer_key == *initializer.key {
+                msg!("Introducer cannot refer themselves");
+                return Err(IntroError::SelfReferral.into());
+            }
+            // Referred wallets must hold stake, so farming the reward with fresh wallets is not free
+            if tier < Referral::MIN_REFEREE_TIER {
+                msg!("Referee must be at stake tier {} or above", Referral::MIN_REFEREE_TIER);
+                return Err(IntroError::InsufficientStake.into());
+            }
+            Some(ReferralAccounts::parse(remaining_iter, &referrer_key)?)
+        },
+        None => None,
+    };
 
-    let rent = Rent::get()?;
+    /*** LOAD OR CREATE INTRO COUNTER PDA ***/
+    let index = next_intro_index(program_id, initializer, pda_intro_counter, system_program, &rent)?;
+    let index_bytes = index.to_be_bytes();
+
+    /*** RECORD REFERRAL AND REWARD REFERRER ***/
+    if let Some(ReferralAccounts { referrer, pda_referral, pda_referrer_vesting }) = referral {
+        if index != 0 {
+            msg!("{} already has {} intros", initializer.key, index);
+            return Err(IntroError::NotFirstIntro.into());
+        }
+
+        // Validate pda_referral is expected
+        let pda_seed = pda::referral_seeds(initializer.key);
+        let bump_seed = find_seeds(pda_referral, &pda_seed, program_id)?;
+
+        // Creation fails if the referral already exists, so each wallet is referred once
+        let account_len = Referral::SIZE;
+        msg!("Creating Referral PDA");
+        create_pda_account(
+            initializer,
+            pda_referral,
+            system_program,
+            program_id,
+            rent.minimum_balance(account_len),
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+
+        let referral_data = Referral {
+            discriminator: Referral::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            referee: *initializer.key,
+            referrer: *referrer.key,
+            reward: config.referral_reward,
+            bump: bump_seed
+        };
+        referral_data.serialize(&mut &mut pda_referral.data.borrow_mut()[..])?;
+        msg!("Referral recorded: {} referred by {}", initializer.key, referrer.key);
+
+        // The reward vests like intro and reply rewards, so it can be clawed back and counts against max supply when claimed
+        if config.referral_reward > 0 {
+            accrue_vesting(program_id, initializer, referrer.key, pda_referrer_vesting, system_program, &rent, config.referral_reward)?;
+        }
+    }
 
     /*** CREATE INTRO PDA ***/
     {
-        // Get PDA
-        let pda_seed = &[initializer.key.as_ref()];
-        let (pda, bump_seed) = Pubkey::find_program_address(pda_seed, program_id);
-
         // Validate pda_intro is expected
-        if pda != *pda_intro.key {
-            msg!("Invalid seeds for Intro PDA");
-            return Err(IntroError::InvalidPDA.into())
-        }
+        let pda_seed = pda::intro_seeds(&community, initializer.key, &index_bytes);
+        let bump_seed = find_seeds(pda_intro, &pda_seed, program_id)?;
 
-        // Ensure data length <1000 bytes
-        let account_len = 1000;
+        // Ensure data length <1000 bytes, plus whatever the stake tier allows
+        let account_len = 1000 + extra_bytes;
         if IntroAccountState::get_account_size(name.clone(), message.clone()) > account_len {
-            msg!("Intro Account data larger than 1000 bytes");
+            msg!("Intro Account data larger than {} bytes", account_len);
             return Err(IntroError::InvalidDataLength.into())  
         }
 
@@ -100,26 +231,21 @@ pub fn add_intro(
         let rent_lamports = rent.minimum_balance(account_len);
 
         msg!("Creating Intro PDA");
-        // Create new account
-        let cpi_instruction = &system_instruction::create_account(
-            initializer.key, 
-            pda_intro.key, 
-            rent_lamports, 
-            account_len.try_into().unwrap(), 
-            program_id
-        );
-    
-        let cpi_accounts = &[initializer.clone(), pda_intro.clone(), system_program.clone()];
-        let initializer_seed = initializer.key.as_ref();
-        let bump_seed_casted: &[u8] = &[bump_seed];
-        let signed_seeds:  &[&[&[u8]]] = &[&[initializer_seed, bump_seed_casted]];
-    
-        invoke_signed(cpi_instruction, cpi_accounts, signed_seeds)?;
-        msg!("Intro PDA created: {}", pda);
+        create_pda_account(
+            initializer,
+            pda_intro,
+            system_program,
+            program_id,
+            rent_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Intro PDA created: {}", pda_intro.key);
     
         // Update account data
         msg!("unpacking Intro PDA");
-        let mut account_data = try_from_slice_unchecked::<IntroAccountState>(&pda_intro.data.borrow()).unwrap();
+        let mut account_data = decode_account::<IntroAccountState>(pda_intro)?;
         msg!("borrowed Intro PDA data");
     
         // Account is_initialized validation
@@ -133,6 +259,12 @@ pub fn add_intro(
         account_data.introducer = *initializer.key;
         account_data.name = name;
         account_data.message = message;
+        account_data.index = index;
+        account_data.community = community;
+        account_data.bump = bump_seed;
+        account_data.content_hash = content_hash;
+        account_data.boost_score = 0;
+        account_data.boost_slot = 0;
     
         // Take 'account_data' object, serialize it, set it to writer object
         account_data.serialize(&mut &mut pda_intro.data.borrow_mut()[..])?;
@@ -141,16 +273,10 @@ pub fn add_intro(
 
     /*** CREATE REPLY COUNTER PDA ***/
     {
-        // Get PDA
-        let reply_counter_seed = "reply";
-        let pda_seed = &[initializer.key.as_ref(), reply_counter_seed.as_ref()];
-        let (pda, bump_seed) = Pubkey::find_program_address(pda_seed, program_id);
-
         // Validate pda_counter is expected
-        if pda != *pda_counter.key {
-            msg!("Invalid seeds for Reply Counter PDA");
-            return Err(IntroError::InvalidPDA.into())
-        }
+        // Reply counter is per-intro now that a wallet can own several intros
+        let pda_seed = pda::reply_counter_seeds(pda_intro.key);
+        let bump_seed = find_seeds(pda_counter, &pda_seed, program_id)?;
 
         // Ensure data length <1000 bytes
         let account_len = IntroReplyCounter::SIZE;
@@ -158,87 +284,35 @@ pub fn add_intro(
         // Compute rent
         let rent_lamports = rent.minimum_balance(account_len);
 
-        msg!("Creating Intro PDA");
-        // Create new account
-        let cpi_instruction = &system_instruction::create_account(
-            initializer.key, 
-            pda_counter.key, 
-            rent_lamports, 
-            account_len.try_into().unwrap(), 
-            program_id
-        );
-    
-        let cpi_accounts = &[initializer.clone(), pda_counter.clone(), system_program.clone()];
-
-        let initializer_seed: &[u8] = initializer.key.as_ref();
-        let reply_counter_seed_casted: &[u8] = reply_counter_seed.as_ref();
-        let bump_seed_casted: &[u8] = &[bump_seed];
-        let signed_seeds:  &[&[&[u8]]] = &[&[initializer_seed, reply_counter_seed_casted, bump_seed_casted]];
-    
-        invoke_signed(cpi_instruction, cpi_accounts, signed_seeds)?;
-        msg!("Reply Counter PDA created: {}", pda);
+        msg!("Creating Reply Counter PDA");
+        create_pda_account(
+            initializer,
+            pda_counter,
+            system_program,
+            program_id,
+            rent_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Reply Counter PDA created: {}", pda_counter.key);
     
-        // Update account data
-        msg!("unpacking Reply Counter PDA");
-        let mut account_data = try_from_slice_unchecked::<IntroReplyCounter>(&pda_counter.data.borrow()).unwrap();
+        // Counter has a fixed layout, write it in place
+        let mut counter_bytes = pda_counter.data.borrow_mut();
+        let account_data = IntroReplyCounterZc::load_mut(&mut counter_bytes)?;
 
-        // let mut account_data = IntroReplyCounter::try_from_slice_unchecked(&pda_counter.data.borrow()).unwrap();
-        msg!("borrowed Reply Counter PDA data");
-    
         // Account is_initialized validation
         if account_data.is_initialized() {
             msg!("Reply Counter PDA already initialized");
             return Err(ProgramError::AccountAlreadyInitialized);
         }
-    
-        account_data.discriminator = IntroReplyCounter::DISCRIMINATOR.to_string();
-        account_data.is_initialized = true;
-        account_data.counter = 0;
 
-        // Take 'account_data' object, serialize it, set it to writer object
-        account_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
+        account_data.initialize(bump_seed);
         msg!("Reply Counter PDA initialized");
     }
 
-    /*** MINT TOKENS TO INITIALIZER ***/
-    {
-        if is_account_initialized(user_ata) == false {
-            msg!("ATA not created, creating...");
-            let instruction = create_associated_token_account(initializer.key, initializer.key, token_mint.key, token_program.key);
-            // https://docs.rs/spl-associated-token-account/latest/spl_associated_token_account/fn.create_associated_token_account.html
-            // Sigh wtf, finally found the correct arguments here - https://solana.stackexchange.com/questions/11943/create-ata-from-program-for-a-pda
-            invoke(
-                &instruction,
-                &[
-                    initializer.clone(),
-                    user_ata.clone(),
-                    token_mint.clone(),
-                    system_program.clone(),
-                    token_program.clone(),
-                ]
-            )?;
-            msg!("Created user ATA");
-        }
-
-        msg!("Minting 10 tokens to User associated token account");
-        invoke_signed(
-            // Instruction
-            &spl_token::instruction::mint_to(
-                token_program.key,
-                token_mint.key,
-                user_ata.key,
-                mint_auth.key,
-                &[],
-                10*LAMPORTS_PER_SOL,
-            )?,
-            // Account_infos
-            &[token_mint.clone(), user_ata.clone(), mint_auth.clone()],
-            // Seeds
-            &[&[b"token_auth", &[mint_auth_bump]]],
-        )?;
-        msg!("Minted 10 tokens to User associated token account");
-        
-    }
+    /*** ACCRUE REWARD TO INITIALIZER ***/
+    accrue_vesting(program_id, initializer, initializer.key, pda_vesting, system_program, &rent, reward_amount)?;
 
     Ok(())
 }
@@ -0,0 +1,166 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use crate::state::{Config, IntroReply, IntroReplyCounterZc};
+use crate::error::IntroError;
+use crate::utils::load_community::load_community;
+use crate::utils::load_config::load_config;
+use crate::utils::load_intro::load_intro;
+use crate::utils::load_reply_counter::load_reply_counter;
+use crate::utils::create_reply_account::create_reply_account;
+use crate::utils::accrue_vesting::accrue_vesting;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_owner};
+use crate::validation::{validate_text, REPLY_LIMITS};
+
+// Keeps the batch inside the transaction size and compute limits
+pub const MAX_BATCH_REPLIES: usize = 10;
+
+pub struct AddRepliesAccounts<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub replier: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_counter: &'a AccountInfo<'info>,
+    pub pda_vesting: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config: Config,
+    // Community account for community-scoped intros, then one Reply PDA per reply
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AddRepliesAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let replier = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_counter = next_account_info(account_info_iter)?;
+        let pda_vesting = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        // Bulk imports are admin-only, the admin pays for every Reply PDA
+        check_signer(admin)?;
+        check_writable(admin)?;
+
+        // The author signs off on replies imported under their key, and on the vested reward
+        check_signer(replier)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+
+        check_owner(pda_counter, program_id)?;
+        check_writable(pda_counter)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        Ok(Self {
+            admin,
+            replier,
+            pda_intro,
+            pda_counter,
+            pda_vesting,
+            system_program,
+            config,
+            remaining_accounts: account_info_iter.as_slice(),
+        })
+    }
+}
+
+// Import several replies by one author in a single transaction, with one vesting accrual for the total reward
+// Imported replies post no bond and leave profile stats untouched
+pub fn add_replies(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    replies: Vec<String>,
+) -> ProgramResult {
+    msg!("Importing {} replies...", replies.len());
+
+    let AddRepliesAccounts {
+        admin,
+        replier,
+        pda_intro,
+        pda_counter,
+        pda_vesting,
+        system_program,
+        config,
+        remaining_accounts,
+    } = AddRepliesAccounts::parse(program_id, accounts)?;
+
+    if replies.is_empty() || replies.len() > MAX_BATCH_REPLIES {
+        msg!("Batch must hold 1 to {} replies", MAX_BATCH_REPLIES);
+        return Err(IntroError::InvalidDataLength.into());
+    }
+
+    for reply in replies.iter() {
+        validate_text(reply, &config.message_limits(REPLY_LIMITS))?;
+    }
+
+    let reply_count = replies.len() as u64;
+    let intro_data = load_intro(program_id, pda_intro)?;
+
+    // Community-scoped intros pass the Community account before the Reply PDAs
+    let account_info_iter = &mut remaining_accounts.iter();
+    let mut reward_per_reply = config.reply_reward_amount();
+    if let Some(community_key) = intro_data.community {
+        let community_account = next_account_info(account_info_iter)?;
+        let community_data = load_community(program_id, community_account, &community_key)?;
+        reward_per_reply = community_data.apply_reward_multiplier(reward_per_reply)?;
+        msg!("Importing into community {}", community_data.name);
+    }
+
+    if account_info_iter.len() != replies.len() {
+        msg!("Expected {} Reply PDAs, got {}", replies.len(), account_info_iter.len());
+        return Err(IntroError::BatchSizeMismatch.into());
+    }
+
+    let mut counter = load_reply_counter(program_id, pda_counter, pda_intro.key)?;
+    let rent = Rent::get()?;
+
+    /*** CREATE REPLY PDAS IN COUNTER ORDER ***/
+    for (reply, pda_reply) in replies.into_iter().zip(account_info_iter) {
+        check_writable(pda_reply)?;
+
+        let next_counter = counter.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+        create_reply_account(
+            program_id,
+            admin,
+            pda_reply,
+            system_program,
+            &rent,
+            counter,
+            IntroReply {
+                discriminator: IntroReply::DISCRIMINATOR.to_string(),
+                is_initialized: true,
+                intro: *pda_intro.key,
+                replier: *replier.key,
+                reply,
+                count: next_counter,
+                community: intro_data.community,
+                bump: 0,
+                content_hash: None
+            }
+        )?;
+        msg!("Comment {} imported", next_counter);
+
+        counter = next_counter;
+    }
+
+    IntroReplyCounterZc::load_mut(&mut pda_counter.data.borrow_mut())?.set_counter(counter);
+    msg!("Counter updated to {}!", counter);
+
+    /*** ACCRUE AGGREGATE REWARD ***/
+    let reward_amount = reward_per_reply.checked_mul(reply_count).ok_or(IntroError::ArithmeticOverflow)?;
+    accrue_vesting(program_id, admin, replier.key, pda_vesting, system_program, &rent, reward_amount)?;
+
+    Ok(())
+}
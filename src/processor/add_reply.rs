@@ -3,188 +3,264 @@ use solana_program::{
     pubkey::Pubkey,
     msg,
     account_info::{next_account_info, AccountInfo},
-    system_instruction,
-    sysvar::{rent::Rent, Sysvar},
-    program::{invoke, invoke_signed},
+    sysvar::{rent::Rent, clock::Clock, Sysvar},
     program_error::ProgramError,
     program_pack::IsInitialized,
-    borsh0_10::try_from_slice_unchecked,
-    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
 };
-use spl_associated_token_account::{instruction::create_associated_token_account, get_associated_token_address};
-use spl_token::ID as TOKEN_PROGRAM_ID;
-use std::convert::TryInto;
 use borsh::BorshSerialize;
-use crate::state::{IntroReplyCounter, IntroReply};
+use crate::state::{Config, IntroReply, IntroReplyCounterZc, ReplyBond, UserProfile};
 use crate::error::IntroError;
 use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::load_community::load_community;
+use crate::utils::load_config::load_config;
+use crate::utils::load_intro::load_intro;
+use crate::utils::load_reply_counter::load_reply_counter;
+use crate::utils::create_reply_account::create_reply_account;
+use crate::utils::accrue_vesting::accrue_vesting;
+use crate::utils::load_stake_tier::load_stake_tier;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_owner, find_seeds};
+use crate::pda;
+use crate::validation::{validate_text, REPLY_LIMITS, CONTENT_URI_LIMITS};
+use crate::utils::decode_account::decode_account;
+
+pub struct AddReplyAccounts<'a, 'info> {
+    pub replier: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_counter: &'a AccountInfo<'info>,
+    pub pda_reply: &'a AccountInfo<'info>,
+    pub pda_bond: &'a AccountInfo<'info>,
+    pub replier_profile: &'a AccountInfo<'info>,
+    pub introducer_profile: &'a AccountInfo<'info>,
+    pub pda_vesting: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config: Config,
+    // Community account for replies to community-scoped intros
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AddReplyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let replier = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_counter = next_account_info(account_info_iter)?;
+        let pda_reply = next_account_info(account_info_iter)?;
+        let pda_bond = next_account_info(account_info_iter)?;
+        let replier_profile = next_account_info(account_info_iter)?;
+        let introducer_profile = next_account_info(account_info_iter)?;
+        let pda_vesting = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        check_signer(replier)?;
+        check_writable(replier)?;
+
+        // Program accounts are invoked, so substituting a fake program must be rejected
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+
+        // Validate that program owns PDAs
+        check_owner(pda_counter, program_id)?;
+        check_writable(pda_counter)?;
+        check_writable(pda_reply)?;
+        check_writable(pda_bond)?;
+
+        // Reward amount and reply limits are governed through Config
+        let config = load_config(program_id, pda_config)?;
+
+        Ok(Self {
+            replier,
+            pda_intro,
+            pda_counter,
+            pda_reply,
+            pda_bond,
+            replier_profile,
+            introducer_profile,
+            pda_vesting,
+            pda_stake,
+            system_program,
+            config,
+            remaining_accounts: account_info_iter.as_slice(),
+        })
+    }
+}
 
 pub fn add_reply(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     reply: String,
+    // Some when `reply` is the URI of off-chain content with this hash
+    content_hash: Option<[u8; 32]>
 ) -> ProgramResult {
     msg!("Adding reply...");
     msg!("Reply: {}", reply);
     
-    // Get Account iterator
-    let account_info_iter = &mut accounts.iter();
-
-    // Get accounts
-    let replier = next_account_info(account_info_iter)?;
-    let pda_intro = next_account_info(account_info_iter)?;
-    let pda_counter = next_account_info(account_info_iter)?;
-    let pda_reply = next_account_info(account_info_iter)?;
-    let token_mint = next_account_info(account_info_iter)?;
-    let mint_auth = next_account_info(account_info_iter)?;
-    let user_ata = next_account_info(account_info_iter)?;
-    let system_program = next_account_info(account_info_iter)?;
-    let token_program = next_account_info(account_info_iter)?;
-    let associated_token_program = next_account_info(account_info_iter)?;
-
-    // Check that `replier` is signer
-    if !replier.is_signer {
-        msg!("Missing required signature");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let AddReplyAccounts {
+        replier,
+        pda_intro,
+        pda_counter,
+        pda_reply,
+        pda_bond,
+        replier_profile,
+        introducer_profile,
+        pda_vesting,
+        pda_stake,
+        system_program,
+        config,
+        remaining_accounts,
+    } = AddReplyAccounts::parse(program_id, accounts)?;
 
-    // Validate that program owns PDAs
-    if pda_intro.owner != program_id {
-        return Err(ProgramError::IllegalOwner)
-    }
+    // Stakers get longer replies, the reply account is sized to the text so nothing else changes
+    let tier = load_stake_tier(program_id, pda_stake, replier.key)?;
 
-    if pda_counter.owner != program_id {
-        return Err(ProgramError::IllegalOwner)
-    }
+    let reply_limits = if content_hash.is_some() { CONTENT_URI_LIMITS } else { config.message_limits(REPLY_LIMITS).for_stake_tier(tier) };
+    validate_text(&reply, &reply_limits)?;
 
-    // Validate token-related accounts
-    msg!("deriving mint authority");
-    let (mint_pda, _mint_bump) = Pubkey::find_program_address(&[b"token_mint"], program_id);
-    let (mint_auth_pda, mint_auth_bump) = Pubkey::find_program_address(&[b"token_auth"], program_id);
+    // Reject replies, counters or other program accounts passed in place of the intro
+    let intro_data = load_intro(program_id, pda_intro)?;
 
-    if *token_mint.key != mint_pda {
-        msg!("Incorrect token mint");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
-    
-    if *mint_auth.key != mint_auth_pda {
-        msg!("Mint passed in and mint derived do not match");
-        return Err(IntroError::InvalidPDA.into());
-    }
-    
-    if *user_ata.key != get_associated_token_address(replier.key, token_mint.key) {
-        msg!("Incorrect token mint");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
-    
-    if *token_program.key != TOKEN_PROGRAM_ID {
-        msg!("Incorrect token program");
-        return Err(IntroError::IncorrectAccountError.into());
+    // Replies to community-scoped intros pass the Community account last
+    let mut reward_amount = config.reply_reward_amount();
+    if let Some(community_key) = intro_data.community {
+        let community_account = next_account_info(&mut remaining_accounts.iter())?;
+        let community_data = load_community(program_id, community_account, &community_key)?;
+        reward_amount = community_data.apply_reward_multiplier(reward_amount)?;
+        msg!("Replying in community {}", community_data.name);
     }
 
-    // Get counter_data
-    let mut counter_data = try_from_slice_unchecked::<IntroReplyCounter>(&pda_counter.data.borrow()).unwrap();
-    let counter_value = counter_data.counter.to_be_bytes();
-
-    // Create Reply PDA
-    let account_len = IntroReply::get_account_size(reply.clone());
-    if account_len > 1000 {
-        msg!("Data length is larger than 1000 bytes");
-        return Err(IntroError::InvalidDataLength.into());
-    };
-
-    // Get PDA
-    let pda_seed = &[pda_intro.key.as_ref(), counter_value.as_ref()];
-    let (pda, bump_seed) = Pubkey::find_program_address(pda_seed, program_id);
-
-    // Validate pda_reply is expected
-    if pda != *pda_reply.key {
-        msg!("Invalid seeds for Reply PDA");
-        return Err(IntroError::InvalidPDA.into())
-    }
+    let counter = load_reply_counter(program_id, pda_counter, pda_intro.key)?;
+    let next_counter = counter.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
 
     let rent = Rent::get()?;
-    let rent_lamports = rent.minimum_balance(account_len);
 
-    msg!("Creating Reply PDA");
-    // Create new account
-    let cpi_instruction = &system_instruction::create_account(
-        replier.key, 
-        pda_reply.key, 
-        rent_lamports,
-        account_len.try_into().unwrap(), 
-        program_id
-    );
+    msg!("Adding comment");
+    create_reply_account(
+        program_id,
+        replier,
+        pda_reply,
+        system_program,
+        &rent,
+        counter,
+        IntroReply {
+            discriminator: IntroReply::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            intro: *pda_intro.key,
+            replier: *replier.key,
+            reply,
+            count: next_counter,
+            community: intro_data.community,
+            bump: 0,
+            content_hash
+        }
+    )?;
+
+    msg!("Comment {} added!", next_counter);
 
-    let cpi_accounts = &[replier.clone(), pda_reply.clone(), system_program.clone()];
+    IntroReplyCounterZc::load_mut(&mut pda_counter.data.borrow_mut())?.set_counter(next_counter);
+    msg!("Counter updated to {}!", next_counter);
 
-    let intro_seed: &[u8] = pda_intro.key.as_ref();
-    let counter_seed_casted: &[u8] = counter_value.as_ref();
-    let bump_seed_casted: &[u8] = &[bump_seed];
-    let signed_seeds:  &[&[&[u8]]] = &[&[intro_seed, counter_seed_casted, bump_seed_casted]];
+    // Escrow reply bond
+    {
+        // Validate pda_bond is expected
+        let pda_seed = pda::bond_seeds(pda_reply.key);
+        let bump_seed = find_seeds(pda_bond, &pda_seed, program_id)?;
 
-    invoke_signed(cpi_instruction, cpi_accounts, signed_seeds)?;
-    msg!("Reply Counter PDA created: {}", pda);
+        // Vault holds rent plus the bond itself
+        let account_len = ReplyBond::SIZE;
+        let vault_lamports = rent.minimum_balance(account_len)
+            .checked_add(ReplyBond::AMOUNT)
+            .ok_or(IntroError::ArithmeticOverflow)?;
 
-    msg!("Adding comment");
-    let mut reply_data = try_from_slice_unchecked::<IntroReply>(&pda_reply.data.borrow()).unwrap();
+        msg!("Creating Reply Bond PDA");
+        create_pda_account(
+            replier,
+            pda_bond,
+            system_program,
+            program_id,
+            vault_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Reply Bond PDA created: {}", pda_bond.key);
+
+        let mut bond_data = decode_account::<ReplyBond>(pda_bond)?;
+
+        if bond_data.is_initialized() {
+            msg!("Reply Bond PDA already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let clock = Clock::get()?;
 
-    if reply_data.is_initialized() {
-        msg!("Account already initialized");
-        return Err(ProgramError::AccountAlreadyInitialized);
+        bond_data.discriminator = ReplyBond::DISCRIMINATOR.to_string();
+        bond_data.is_initialized = true;
+        bond_data.intro = *pda_intro.key;
+        bond_data.reply = *pda_reply.key;
+        bond_data.replier = *replier.key;
+        bond_data.amount = ReplyBond::AMOUNT;
+        bond_data.challenge_end_slot = clock.slot
+            .checked_add(ReplyBond::CHALLENGE_WINDOW_SLOTS)
+            .ok_or(IntroError::ArithmeticOverflow)?;
+        bond_data.bump = bump_seed;
+
+        bond_data.serialize(&mut &mut pda_bond.data.borrow_mut()[..])?;
+        msg!("Escrowed {} lamports until slot {}", bond_data.amount, bond_data.challenge_end_slot);
     }
 
-    reply_data.discriminator = IntroReply::DISCRIMINATOR.to_string();
-    reply_data.is_initialized = true;
-    reply_data.intro = *pda_intro.key;
-    reply_data.replier = *replier.key;
-    reply_data.reply = reply;
-    reply_data.count = counter_data.counter + 1;
-
-    reply_data.serialize(&mut &mut pda_reply.data.borrow_mut()[..])?;
-
-    msg!("Comment {} added!", counter_data.counter + 1);
-
-    counter_data.counter += 1;
-    counter_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
-    msg!("Counter updated to {}!", counter_data.counter);
-
-    // Mint tokens
-
-    if is_account_initialized(user_ata) == false {
-        msg!("ATA not created, creating...");
-        let instruction = create_associated_token_account(replier.key, replier.key, token_mint.key, token_program.key);
-        // https://docs.rs/spl-associated-token-account/latest/spl_associated_token_account/fn.create_associated_token_account.html
-        invoke(
-            &instruction,
-            &[
-                replier.clone(),
-                user_ata.clone(),
-                token_mint.clone(),
-                system_program.clone(),
-                token_program.clone(),
-            ]
-        )?;
-        msg!("Created user ATA");
+    // Update profile stats in the same instruction as the reply and the mint
+    let introducer = intro_data.introducer;
+
+    update_profile_counters(program_id, replier_profile, replier.key, |profile| {
+        profile.replies_written = profile.replies_written.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+        profile.tokens_earned = profile.tokens_earned.checked_add(reward_amount).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(())
+    })?;
+
+    update_profile_counters(program_id, introducer_profile, &introducer, |profile| {
+        profile.replies_received = profile.replies_received.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(())
+    })?;
+
+    // Accrue reward to replier
+    accrue_vesting(program_id, replier, replier.key, pda_vesting, system_program, &rent, reward_amount)?;
+
+    Ok(())
+}
+
+// Profiles are opt-in, so counters are only written once the wallet has created one
+fn update_profile_counters<F: FnOnce(&mut UserProfile) -> ProgramResult>(
+    program_id: &Pubkey,
+    pda_profile: &AccountInfo,
+    wallet: &Pubkey,
+    update: F
+) -> ProgramResult {
+    // Validate pda_profile is expected, so an existing profile cannot be swapped for an empty account
+    find_seeds(pda_profile, &pda::profile_seeds(wallet), program_id)?;
+
+    if !is_account_initialized(pda_profile) {
+        msg!("No profile for {}, skipping stats", wallet);
+        return Ok(());
     }
 
-    msg!("Minting 5 tokens to User associated token account");
-    invoke_signed(
-        // Instruction
-        &spl_token::instruction::mint_to(
-            token_program.key,
-            token_mint.key,
-            user_ata.key,
-            mint_auth.key,
-            &[],
-            5 * LAMPORTS_PER_SOL,
-        )?,
-        // Account_infos
-        &[token_mint.clone(), user_ata.clone(), mint_auth.clone()],
-        // Seeds
-        &[&[b"token_auth", &[mint_auth_bump]]],
-    )?;
-    msg!("Minted 5 tokens to User associated token account");
+    // Validate that program owns PDA
+    check_owner(pda_profile, program_id)?;
+
+    let mut profile_data = decode_account::<UserProfile>(pda_profile)?;
+
+    if !profile_data.is_initialized() || profile_data.discriminator != UserProfile::DISCRIMINATOR {
+        msg!("Profile PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_writable(pda_profile)?;
+    update(&mut profile_data)?;
+    profile_data.serialize(&mut &mut pda_profile.data.borrow_mut()[..])?;
+    msg!("Profile stats updated for {}", wallet);
 
     Ok(())
 }
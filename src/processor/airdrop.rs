@@ -0,0 +1,131 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use crate::state::Config;
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::mint_reward::mint_reward;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds, check_ata};
+use crate::pda;
+
+// Keeps an airdrop within one transaction's account and compute limits
+pub const MAX_AIRDROP_RECIPIENTS: usize = 10;
+
+pub struct AirdropAccounts<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub pda_config: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub mint_auth: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub config: Config,
+    // (wallet, ATA) pair per recipient
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AirdropAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let mint_auth = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+
+        check_signer(admin)?;
+        check_writable(admin)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_writable(token_mint)?;
+        check_seeds(mint_auth, &pda::token_auth_seeds(), config.mint_auth_bump, program_id)?;
+
+        Ok(Self {
+            admin,
+            pda_config,
+            token_mint,
+            mint_auth,
+            system_program,
+            token_program,
+            associated_token_program,
+            config,
+            remaining_accounts: account_info_iter.as_slice(),
+        })
+    }
+}
+
+// Mint reward tokens straight to a list of wallets, counted against the max supply like every other mint
+pub fn airdrop(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amounts: Vec<u64>
+) -> ProgramResult {
+    msg!("Airdropping to {} recipients...", amounts.len());
+
+    let AirdropAccounts {
+        admin,
+        pda_config,
+        token_mint,
+        mint_auth,
+        system_program,
+        token_program,
+        associated_token_program,
+        mut config,
+        remaining_accounts,
+    } = AirdropAccounts::parse(program_id, accounts)?;
+
+    if amounts.is_empty() || amounts.len() > MAX_AIRDROP_RECIPIENTS {
+        msg!("Airdrop must have 1 to {} recipients", MAX_AIRDROP_RECIPIENTS);
+        return Err(IntroError::InvalidDataLength.into());
+    }
+
+    if remaining_accounts.len() != amounts.len() * 2 {
+        msg!("Expected {} recipient accounts, got {}", amounts.len() * 2, remaining_accounts.len());
+        return Err(IntroError::BatchSizeMismatch.into());
+    }
+
+    for (amount, recipient) in amounts.into_iter().zip(remaining_accounts.chunks_exact(2)) {
+        let (wallet, user_ata) = (&recipient[0], &recipient[1]);
+        check_ata(user_ata, wallet.key, token_mint.key)?;
+        check_writable(user_ata)?;
+
+        mint_reward(
+            &mut config,
+            pda_config,
+            admin,
+            wallet,
+            user_ata,
+            token_mint,
+            mint_auth,
+            system_program,
+            token_program,
+            associated_token_program,
+            amount
+        )?;
+    }
+
+    msg!("Airdrop done, {} tokens minted in total", config.total_minted / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
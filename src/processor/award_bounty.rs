@@ -0,0 +1,166 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::{instruction::create_associated_token_account, ID as ASSOCIATED_TOKEN_PROGRAM_ID};
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use crate::state::IntroReply;
+use crate::error::IntroError;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::load_bounty::load_bounty;
+use crate::utils::load_config::load_config;
+use crate::utils::release_bounty::release_bounty;
+use crate::utils::decode_account::decode_account;
+use crate::utils::validators::{check_signer, check_writable, check_owner, check_program_id, check_seeds, check_ata};
+use crate::pda;
+
+pub struct AwardBountyAccounts<'a, 'info> {
+    pub introducer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_bounty: &'a AccountInfo<'info>,
+    pub pda_reply: &'a AccountInfo<'info>,
+    pub replier: &'a AccountInfo<'info>,
+    pub replier_ata: &'a AccountInfo<'info>,
+    pub introducer_ata: &'a AccountInfo<'info>,
+    pub escrow_ata: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AwardBountyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let introducer = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_bounty = next_account_info(account_info_iter)?;
+        let pda_reply = next_account_info(account_info_iter)?;
+        let replier = next_account_info(account_info_iter)?;
+        let replier_ata = next_account_info(account_info_iter)?;
+        let introducer_ata = next_account_info(account_info_iter)?;
+        let escrow_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(introducer)?;
+        check_writable(introducer)?;
+        check_writable(pda_bounty)?;
+        check_owner(pda_reply, program_id)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_ata(replier_ata, replier.key, token_mint.key)?;
+        check_writable(replier_ata)?;
+        check_ata(introducer_ata, introducer.key, token_mint.key)?;
+        check_writable(introducer_ata)?;
+        check_ata(escrow_ata, pda_bounty.key, token_mint.key)?;
+        check_writable(escrow_ata)?;
+
+        Ok(Self {
+            introducer,
+            pda_intro,
+            pda_bounty,
+            pda_reply,
+            replier,
+            replier_ata,
+            introducer_ata,
+            escrow_ata,
+            token_mint,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+// Pay the bounty on an intro to the author of one of its replies
+pub fn award_bounty(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Awarding bounty...");
+
+    let AwardBountyAccounts {
+        introducer,
+        pda_intro,
+        pda_bounty,
+        pda_reply,
+        replier,
+        replier_ata,
+        introducer_ata,
+        escrow_ata,
+        token_mint,
+        system_program,
+        token_program,
+        associated_token_program,
+    } = AwardBountyAccounts::parse(program_id, accounts)?;
+
+    let bounty_data = load_bounty(program_id, pda_bounty, pda_intro.key, introducer.key)?;
+
+    let reply_data = decode_account::<IntroReply>(pda_reply)?;
+
+    if !reply_data.is_initialized() || reply_data.discriminator != IntroReply::DISCRIMINATOR {
+        msg!("Account {} is not a reply", pda_reply.key);
+        return Err(IntroError::IncorrectAccountError.into());
+    }
+
+    // Reply must be one of this intro's replies, numbered count - 1 under it
+    if reply_data.intro != *pda_intro.key {
+        msg!("Reply does not belong to this intro");
+        return Err(IntroError::IncorrectAccountError.into());
+    }
+    let counter_value = reply_data.count.checked_sub(1).ok_or(IntroError::ArithmeticOverflow)?.to_be_bytes();
+    check_seeds(pda_reply, &pda::reply_seeds(pda_intro.key, &counter_value), reply_data.bump, program_id)?;
+
+    if reply_data.replier != *replier.key {
+        msg!("Replier passed in does not match reply author");
+        return Err(IntroError::IncorrectAccountError.into());
+    }
+
+    // Awarding yourself would be an early cancel
+    if reply_data.replier == bounty_data.introducer {
+        msg!("Introducer wrote this reply");
+        return Err(IntroError::SelfAward.into());
+    }
+
+    // Extra tokens sent to the escrow go back to the introducer, whose ATA may have been closed since
+    for (wallet, ata) in [(replier, replier_ata), (introducer, introducer_ata)] {
+        if !is_account_initialized(ata) {
+            msg!("ATA of {} not created, creating...", wallet.key);
+            let instruction = create_associated_token_account(introducer.key, wallet.key, token_mint.key, token_program.key);
+            invoke(
+                &instruction,
+                &[
+                    introducer.clone(),
+                    ata.clone(),
+                    wallet.clone(),
+                    token_mint.clone(),
+                    system_program.clone(),
+                    token_program.clone(),
+                    associated_token_program.clone(),
+                ]
+            )?;
+            msg!("Created ATA for {}", wallet.key);
+        }
+    }
+
+    release_bounty(&bounty_data, pda_bounty, escrow_ata, replier_ata, introducer_ata, introducer, token_program)?;
+    msg!("Bounty awarded to {}", replier.key);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program::invoke,
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::load_intro::load_intro;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds, check_ata};
+use crate::pda;
+
+pub struct BoostIntroAccounts<'a, 'info> {
+    pub booster: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub user_ata: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> BoostIntroAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let booster = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let user_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(booster)?;
+        check_writable(pda_intro)?;
+
+        // Only the reward token can be burned for a boost
+        let config = load_config(program_id, pda_config)?;
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_writable(token_mint)?;
+        check_ata(user_ata, booster.key, token_mint.key)?;
+        check_writable(user_ata)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+
+        Ok(Self { booster, pda_intro, user_ata, token_mint, token_program })
+    }
+}
+
+// Burn reward tokens from the booster's ATA to raise an intro's boost score, any wallet can boost any intro
+pub fn boost_intro(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64
+) -> ProgramResult {
+    msg!("Boosting intro with {} tokens...", amount / LAMPORTS_PER_SOL);
+
+    let BoostIntroAccounts { booster, pda_intro, user_ata, token_mint, token_program } = BoostIntroAccounts::parse(program_id, accounts)?;
+
+    if amount == 0 {
+        msg!("Boost amount must be positive");
+        return Err(IntroError::ZeroAmount.into());
+    }
+
+    let mut intro_data = load_intro(program_id, pda_intro)?;
+
+    invoke(
+        &spl_token::instruction::burn(
+            token_program.key,
+            user_ata.key,
+            token_mint.key,
+            booster.key,
+            &[],
+            amount,
+        )?,
+        &[user_ata.clone(), token_mint.clone(), booster.clone()],
+    )?;
+    msg!("Burned {} tokens", amount / LAMPORTS_PER_SOL);
+
+    let clock = Clock::get()?;
+    intro_data.boost(amount, clock.slot)?;
+
+    intro_data.serialize(&mut &mut pda_intro.data.borrow_mut()[..])?;
+    msg!("Boost score of {} is now {}", pda_intro.key, intro_data.boost_score);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+};
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use crate::error::IntroError;
+use crate::utils::load_bounty::load_bounty;
+use crate::utils::load_config::load_config;
+use crate::utils::release_bounty::release_bounty;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds, check_ata};
+use crate::pda;
+
+pub struct CancelBountyAccounts<'a, 'info> {
+    pub introducer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_bounty: &'a AccountInfo<'info>,
+    pub introducer_ata: &'a AccountInfo<'info>,
+    pub escrow_ata: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CancelBountyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let introducer = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_bounty = next_account_info(account_info_iter)?;
+        let introducer_ata = next_account_info(account_info_iter)?;
+        let escrow_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(introducer)?;
+        check_writable(introducer)?;
+        check_writable(pda_bounty)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_ata(introducer_ata, introducer.key, token_mint.key)?;
+        check_writable(introducer_ata)?;
+        check_ata(escrow_ata, pda_bounty.key, token_mint.key)?;
+        check_writable(escrow_ata)?;
+
+        Ok(Self { introducer, pda_intro, pda_bounty, introducer_ata, escrow_ata, token_program })
+    }
+}
+
+// Return an unawarded bounty to the introducer once it has expired
+pub fn cancel_bounty(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Cancelling bounty...");
+
+    let CancelBountyAccounts {
+        introducer,
+        pda_intro,
+        pda_bounty,
+        introducer_ata,
+        escrow_ata,
+        token_program,
+    } = CancelBountyAccounts::parse(program_id, accounts)?;
+
+    let bounty_data = load_bounty(program_id, pda_bounty, pda_intro.key, introducer.key)?;
+
+    let clock = Clock::get()?;
+    if clock.slot < bounty_data.expiry_slot {
+        msg!("Bounty expires at slot {}", bounty_data.expiry_slot);
+        return Err(IntroError::BountyNotExpired.into());
+    }
+
+    release_bounty(&bounty_data, pda_bounty, escrow_ata, introducer_ata, introducer_ata, introducer, token_program)?;
+    msg!("Bounty returned to introducer");
+
+    Ok(())
+}
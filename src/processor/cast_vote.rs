@@ -0,0 +1,121 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::VoteRecord;
+use crate::error::IntroError;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::load_proposal::load_proposal;
+use crate::utils::load_stake::load_stake;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+use crate::pda;
+
+pub struct CastVoteAccounts<'a, 'info> {
+    pub voter: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub pda_proposal: &'a AccountInfo<'info>,
+    pub pda_vote: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CastVoteAccounts<'a, 'info> {
+    pub fn parse(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            voter: next_account_info(account_info_iter)?,
+            pda_stake: next_account_info(account_info_iter)?,
+            pda_proposal: next_account_info(account_info_iter)?,
+            pda_vote: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+        };
+
+        check_signer(ctx.voter)?;
+        check_writable(ctx.voter)?;
+        check_writable(ctx.pda_proposal)?;
+        check_writable(ctx.pda_vote)?;
+        check_program_id(ctx.system_program, &SYSTEM_PROGRAM_ID)?;
+
+        Ok(ctx)
+    }
+}
+
+// Vote on an open proposal with the voter's current stake, once per wallet
+pub fn cast_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approve: bool
+) -> ProgramResult {
+    msg!("Casting vote...");
+
+    let CastVoteAccounts { voter, pda_stake, pda_proposal, pda_vote, system_program } = CastVoteAccounts::parse(accounts)?;
+
+    let mut proposal_data = load_proposal(program_id, pda_proposal)?;
+
+    let clock = Clock::get()?;
+    if clock.slot >= proposal_data.end_slot {
+        msg!("Voting ended at slot {}", proposal_data.end_slot);
+        return Err(IntroError::VotingClosed.into());
+    }
+
+    // Unstaked tokens stay unbonding until voting ends, so the same tokens cannot vote twice
+    let weight = load_stake(program_id, pda_stake, voter.key)?.amount;
+    if weight == 0 {
+        msg!("Voting needs staked tokens");
+        return Err(IntroError::InsufficientStake.into());
+    }
+
+    /*** CREATE VOTE RECORD PDA ***/
+    // Creation fails if the voter already voted
+    let pda_seed = pda::vote_seeds(pda_proposal.key, voter.key);
+    let bump_seed = find_seeds(pda_vote, &pda_seed, program_id)?;
+
+    let account_len = VoteRecord::SIZE;
+    let rent = Rent::get()?;
+
+    msg!("Creating Vote Record PDA");
+    create_pda_account(
+        voter,
+        pda_vote,
+        system_program,
+        program_id,
+        rent.minimum_balance(account_len),
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+
+    let vote_data = VoteRecord {
+        discriminator: VoteRecord::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        proposal: *pda_proposal.key,
+        voter: *voter.key,
+        approve,
+        weight,
+        bump: bump_seed
+    };
+    vote_data.serialize(&mut &mut pda_vote.data.borrow_mut()[..])?;
+
+    if approve {
+        proposal_data.yes_votes = proposal_data.yes_votes.checked_add(weight).ok_or(IntroError::ArithmeticOverflow)?;
+    } else {
+        proposal_data.no_votes = proposal_data.no_votes.checked_add(weight).ok_or(IntroError::ArithmeticOverflow)?;
+    }
+    proposal_data.serialize(&mut &mut pda_proposal.data.borrow_mut()[..])?;
+    msg!(
+        "Voted {} with {} tokens, {} yes / {} no",
+        if approve { "yes" } else { "no" },
+        weight / LAMPORTS_PER_SOL,
+        proposal_data.yes_votes / LAMPORTS_PER_SOL,
+        proposal_data.no_votes / LAMPORTS_PER_SOL
+    );
+
+    Ok(())
+}
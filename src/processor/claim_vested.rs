@@ -0,0 +1,119 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use borsh::BorshSerialize;
+use crate::state::Config;
+use crate::utils::load_config::load_config;
+use crate::utils::load_vesting::load_vesting;
+use crate::utils::mint_reward::mint_reward;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_reward_accounts};
+
+pub struct ClaimVestedAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_vesting: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub mint_auth: &'a AccountInfo<'info>,
+    pub user_ata: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub pda_config: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> ClaimVestedAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let wallet = next_account_info(account_info_iter)?;
+        let pda_vesting = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let mint_auth = next_account_info(account_info_iter)?;
+        let user_ata = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(wallet)?;
+        check_writable(wallet)?;
+        check_writable(pda_vesting)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        // Mint and mint authority are re-derived from the bumps stored in Config
+        let config = load_config(program_id, pda_config)?;
+        check_reward_accounts(program_id, &config, token_mint, mint_auth, user_ata, wallet.key, token_program)?;
+
+        Ok(Self {
+            wallet,
+            pda_vesting,
+            token_mint,
+            mint_auth,
+            user_ata,
+            system_program,
+            token_program,
+            associated_token_program,
+            pda_config,
+            config,
+        })
+    }
+}
+
+// Mint everything unlocked so far in the wallet's vesting account
+pub fn claim_vested(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Claiming vested rewards...");
+
+    let ClaimVestedAccounts {
+        wallet,
+        pda_vesting,
+        token_mint,
+        mint_auth,
+        user_ata,
+        system_program,
+        token_program,
+        associated_token_program,
+        pda_config,
+        mut config,
+    } = ClaimVestedAccounts::parse(program_id, accounts)?;
+
+    let mut vesting_data = load_vesting(program_id, pda_vesting, wallet.key)?;
+
+    let clock = Clock::get()?;
+    let amount = vesting_data.claim(clock.slot)?;
+    if amount == 0 {
+        msg!("Nothing unlocked yet");
+        return Ok(());
+    }
+
+    vesting_data.serialize(&mut &mut pda_vesting.data.borrow_mut()[..])?;
+
+    mint_reward(
+        &mut config,
+        pda_config,
+        wallet,
+        wallet,
+        user_ata,
+        token_mint,
+        mint_auth,
+        system_program,
+        token_program,
+        associated_token_program,
+        amount
+    )?;
+    msg!("Claimed {} tokens, {} claimed in total", amount / LAMPORTS_PER_SOL, vesting_data.claimed / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::load_vesting::load_vesting;
+use crate::utils::validators::{check_signer, check_writable};
+
+pub struct ClawBackVestingAccounts<'a, 'info> {
+    pub admin: &'a AccountInfo<'info>,
+    pub pda_vesting: &'a AccountInfo<'info>,
+    pub wallet: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ClawBackVestingAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let pda_vesting = next_account_info(account_info_iter)?;
+        let wallet = next_account_info(account_info_iter)?;
+
+        check_signer(admin)?;
+        check_writable(pda_vesting)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        Ok(Self { admin, pda_vesting, wallet })
+    }
+}
+
+// Cancel the still-locked rewards of an abusive wallet, what has already vested stays claimable
+pub fn claw_back_vesting(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Clawing back unvested rewards...");
+
+    let ClawBackVestingAccounts { admin, pda_vesting, wallet } = ClawBackVestingAccounts::parse(program_id, accounts)?;
+
+    let mut vesting_data = load_vesting(program_id, pda_vesting, wallet.key)?;
+
+    let clock = Clock::get()?;
+    let clawed = vesting_data.claw_back(clock.slot)?;
+
+    vesting_data.serialize(&mut &mut pda_vesting.data.borrow_mut()[..])?;
+    msg!("Admin {} clawed back {} tokens from {}", admin.key, clawed / LAMPORTS_PER_SOL, wallet.key);
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, clock::Clock, Sysvar},
+    program::invoke,
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::{instruction::create_associated_token_account_idempotent, ID as ASSOCIATED_TOKEN_PROGRAM_ID};
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use borsh::BorshSerialize;
+use crate::state::Bounty;
+use crate::error::IntroError;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::load_config::load_config;
+use crate::utils::load_intro::load_intro;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds, check_ata, find_seeds};
+use crate::pda;
+
+pub struct CreateBountyAccounts<'a, 'info> {
+    pub introducer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_bounty: &'a AccountInfo<'info>,
+    pub introducer_ata: &'a AccountInfo<'info>,
+    pub escrow_ata: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CreateBountyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let introducer = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_bounty = next_account_info(account_info_iter)?;
+        let introducer_ata = next_account_info(account_info_iter)?;
+        let escrow_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(introducer)?;
+        check_writable(introducer)?;
+        check_writable(pda_bounty)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        // Bounties are paid in the reward token only
+        let config = load_config(program_id, pda_config)?;
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_ata(introducer_ata, introducer.key, token_mint.key)?;
+        check_writable(introducer_ata)?;
+        check_ata(escrow_ata, pda_bounty.key, token_mint.key)?;
+        check_writable(escrow_ata)?;
+
+        Ok(Self {
+            introducer,
+            pda_intro,
+            pda_bounty,
+            introducer_ata,
+            escrow_ata,
+            token_mint,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+// Escrow reward tokens on an intro until the introducer awards them to a reply or cancels after `expiry_slot`
+pub fn create_bounty(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    expiry_slot: u64
+) -> ProgramResult {
+    msg!("Creating bounty of {} tokens...", amount / LAMPORTS_PER_SOL);
+
+    let CreateBountyAccounts {
+        introducer,
+        pda_intro,
+        pda_bounty,
+        introducer_ata,
+        escrow_ata,
+        token_mint,
+        system_program,
+        token_program,
+        associated_token_program,
+    } = CreateBountyAccounts::parse(program_id, accounts)?;
+
+    let intro_data = load_intro(program_id, pda_intro)?;
+
+    // Only the intro owner can put a bounty on it
+    if intro_data.introducer != *introducer.key {
+        msg!("Signer is not the intro owner");
+        return Err(IntroError::Unauthorized.into());
+    }
+
+    if amount == 0 {
+        msg!("Bounty amount must be positive");
+        return Err(IntroError::ZeroAmount.into());
+    }
+
+    let clock = Clock::get()?;
+    if expiry_slot <= clock.slot {
+        msg!("Bounty expiry slot {} is not in the future", expiry_slot);
+        return Err(IntroError::ExpiryInPast.into());
+    }
+
+    /*** CREATE BOUNTY PDA ***/
+    // Validate pda_bounty is expected
+    let pda_seed = pda::bounty_seeds(pda_intro.key);
+    let bump_seed = find_seeds(pda_bounty, &pda_seed, program_id)?;
+
+    let account_len = Bounty::SIZE;
+    let rent = Rent::get()?;
+
+    msg!("Creating Bounty PDA");
+    create_pda_account(
+        introducer,
+        pda_bounty,
+        system_program,
+        program_id,
+        rent.minimum_balance(account_len),
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+    msg!("Bounty PDA created: {}", pda_bounty.key);
+
+    /*** ESCROW TOKENS ***/
+    // Idempotent, anyone can create the escrow ATA ahead of time since its address is public
+    msg!("Creating escrow ATA");
+    let instruction = create_associated_token_account_idempotent(introducer.key, pda_bounty.key, token_mint.key, token_program.key);
+    invoke(
+        &instruction,
+        &[
+            introducer.clone(),
+            escrow_ata.clone(),
+            pda_bounty.clone(),
+            token_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ]
+    )?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            introducer_ata.key,
+            escrow_ata.key,
+            introducer.key,
+            &[],
+            amount,
+        )?,
+        &[introducer_ata.clone(), escrow_ata.clone(), introducer.clone()],
+    )?;
+
+    let bounty_data = Bounty {
+        discriminator: Bounty::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        intro: *pda_intro.key,
+        introducer: *introducer.key,
+        amount,
+        expiry_slot,
+        bump: bump_seed
+    };
+    bounty_data.serialize(&mut &mut pda_bounty.data.borrow_mut()[..])?;
+    msg!("Escrowed {} tokens until slot {}", amount / LAMPORTS_PER_SOL, expiry_slot);
+
+    Ok(())
+}
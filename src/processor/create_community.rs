@@ -0,0 +1,119 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::Community;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct CreateCommunityAccounts<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub pda_community: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CreateCommunityAccounts<'a, 'info> {
+    pub fn parse(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            creator: next_account_info(account_info_iter)?,
+            pda_community: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `creator` is signer
+        check_signer(ctx.creator)?;
+        check_writable(ctx.creator)?;
+        check_writable(ctx.pda_community)?;
+        check_program_id(ctx.system_program, &SYSTEM_PROGRAM_ID)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn create_community(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    description: String,
+    moderators: Vec<Pubkey>,
+    reward_multiplier: u16
+) -> ProgramResult {
+    msg!("Creating community...");
+    msg!("Name: {}", name);
+
+    let CreateCommunityAccounts { creator, pda_community, system_program } = CreateCommunityAccounts::parse(accounts)?;
+
+    if name.is_empty() || name.len() > Community::MAX_NAME_LEN {
+        msg!("Community name must be 1 to {} bytes", Community::MAX_NAME_LEN);
+        return Err(IntroError::InvalidDataLength.into())
+    }
+
+    if moderators.len() > Community::MAX_MODERATORS {
+        msg!("At most {} moderators", Community::MAX_MODERATORS);
+        return Err(IntroError::InvalidDataLength.into())
+    }
+
+    if reward_multiplier > Community::MAX_REWARD_MULTIPLIER {
+        msg!("Reward multiplier above {}%", Community::MAX_REWARD_MULTIPLIER);
+        return Err(IntroError::InvalidRewardMultiplier.into())
+    }
+
+    // Validate pda_community is expected
+    let bump_seed = find_seeds(pda_community, &pda::community_seeds(&name), program_id)?;
+
+    // Ensure data length <1000 bytes
+    let account_len = 1000;
+    if Community::get_account_size(name.clone(), description.clone(), &moderators) > account_len {
+        msg!("Community Account data larger than 1000 bytes");
+        return Err(IntroError::InvalidDataLength.into())
+    }
+
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(account_len);
+
+    msg!("Creating Community PDA");
+    create_pda_account(
+        creator,
+        pda_community,
+        system_program,
+        program_id,
+        rent_lamports,
+        account_len,
+        &pda::community_seeds(&name),
+        bump_seed
+    )?;
+    msg!("Community PDA created: {}", pda_community.key);
+
+    let mut account_data = decode_account::<Community>(pda_community)?;
+
+    if account_data.is_initialized() {
+        msg!("Community PDA already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    account_data.discriminator = Community::DISCRIMINATOR.to_string();
+    account_data.is_initialized = true;
+    account_data.creator = *creator.key;
+    account_data.name = name;
+    account_data.description = description;
+    account_data.moderators = moderators;
+    account_data.reward_multiplier = reward_multiplier;
+    account_data.bump = bump_seed;
+
+    account_data.serialize(&mut &mut pda_community.data.borrow_mut()[..])?;
+    msg!("Community PDA initialized");
+
+    Ok(())
+}
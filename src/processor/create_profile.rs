@@ -0,0 +1,112 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::UserProfile;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct CreateProfileAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_profile: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CreateProfileAccounts<'a, 'info> {
+    pub fn parse(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            wallet: next_account_info(account_info_iter)?,
+            pda_profile: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `wallet` is signer
+        check_signer(ctx.wallet)?;
+        check_writable(ctx.wallet)?;
+        check_writable(ctx.pda_profile)?;
+        check_program_id(ctx.system_program, &SYSTEM_PROGRAM_ID)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn create_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    display_name: String,
+    avatar_uri: String,
+    bio: String
+) -> ProgramResult {
+    msg!("Creating profile...");
+    msg!("Display name: {}", display_name);
+
+    let CreateProfileAccounts { wallet, pda_profile, system_program } = CreateProfileAccounts::parse(accounts)?;
+
+    // Validate pda_profile is expected
+    let pda_seed = pda::profile_seeds(wallet.key);
+    let bump_seed = find_seeds(pda_profile, &pda_seed, program_id)?;
+
+    // Ensure data length <1000 bytes
+    let account_len = 1000;
+    if UserProfile::get_account_size(display_name.clone(), avatar_uri.clone(), bio.clone()) > account_len {
+        msg!("Profile Account data larger than 1000 bytes");
+        return Err(IntroError::InvalidDataLength.into())
+    }
+
+    // Compute rent
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(account_len);
+
+    msg!("Creating Profile PDA");
+    create_pda_account(
+        wallet,
+        pda_profile,
+        system_program,
+        program_id,
+        rent_lamports,
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+    msg!("Profile PDA created: {}", pda_profile.key);
+
+    let mut account_data = decode_account::<UserProfile>(pda_profile)?;
+
+    // Account is_initialized validation
+    if account_data.is_initialized() {
+        msg!("Profile PDA already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    account_data.discriminator = UserProfile::DISCRIMINATOR.to_string();
+    account_data.is_initialized = true;
+    account_data.wallet = *wallet.key;
+    account_data.display_name = display_name;
+    account_data.avatar_uri = avatar_uri;
+    account_data.bio = bio;
+    account_data.replies_written = 0;
+    account_data.replies_received = 0;
+    account_data.tokens_earned = 0;
+    account_data.follower_count = 0;
+    account_data.following_count = 0;
+    account_data.follow_reward_claimed = false;
+    account_data.bump = bump_seed;
+
+    // Take 'account_data' object, serialize it, set it to writer object
+    account_data.serialize(&mut &mut pda_profile.data.borrow_mut()[..])?;
+    msg!("Profile PDA initialized");
+
+    Ok(())
+}
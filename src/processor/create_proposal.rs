@@ -0,0 +1,123 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::{Config, Proposal, ProposalAction};
+use crate::error::IntroError;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::load_config::load_config;
+use crate::utils::load_stake::load_stake;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+use crate::pda;
+
+pub struct CreateProposalAccounts<'a, 'info> {
+    pub proposer: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub pda_config: &'a AccountInfo<'info>,
+    pub pda_proposal: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> CreateProposalAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let proposer = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let pda_proposal = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        check_signer(proposer)?;
+        check_writable(proposer)?;
+        check_writable(pda_config)?;
+        check_writable(pda_proposal)?;
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+
+        Ok(Self { proposer, pda_stake, pda_config, pda_proposal, system_program, config })
+    }
+}
+
+// Open a vote on a program parameter change, stakers holding at least the proposal threshold can propose
+pub fn create_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action: ProposalAction
+) -> ProgramResult {
+    msg!("Creating proposal {:?}...", action);
+
+    let CreateProposalAccounts {
+        proposer,
+        pda_stake,
+        pda_config,
+        pda_proposal,
+        system_program,
+        mut config,
+    } = CreateProposalAccounts::parse(program_id, accounts)?;
+
+    let stake_data = load_stake(program_id, pda_stake, proposer.key)?;
+    if stake_data.amount < Proposal::PROPOSAL_THRESHOLD {
+        msg!("Proposing needs {} staked tokens", Proposal::PROPOSAL_THRESHOLD / LAMPORTS_PER_SOL);
+        return Err(IntroError::InsufficientStake.into());
+    }
+
+    if let Err(error) = action.check_bounds() {
+        msg!("{:?} is outside the governed bounds", action);
+        return Err(error);
+    }
+
+    /*** CREATE PROPOSAL PDA ***/
+    let index = config.proposal_count;
+    let index_bytes = index.to_be_bytes();
+
+    // Validate pda_proposal is expected
+    let pda_seed = pda::proposal_seeds(&index_bytes);
+    let bump_seed = find_seeds(pda_proposal, &pda_seed, program_id)?;
+
+    let account_len = Proposal::SIZE;
+    let rent = Rent::get()?;
+
+    msg!("Creating Proposal PDA");
+    create_pda_account(
+        proposer,
+        pda_proposal,
+        system_program,
+        program_id,
+        rent.minimum_balance(account_len),
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+    msg!("Proposal PDA created: {}", pda_proposal.key);
+
+    let clock = Clock::get()?;
+    let proposal_data = Proposal {
+        discriminator: Proposal::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        index,
+        proposer: *proposer.key,
+        action,
+        end_slot: clock.slot.checked_add(Proposal::VOTING_SLOTS).ok_or(IntroError::ArithmeticOverflow)?,
+        yes_votes: 0,
+        no_votes: 0,
+        executed: false,
+        bump: bump_seed
+    };
+    proposal_data.serialize(&mut &mut pda_proposal.data.borrow_mut()[..])?;
+
+    config.proposal_count = index.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+    config.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+    msg!("Proposal {} open until slot {}", index, proposal_data.end_slot);
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::ReplyTree;
+use crate::error::IntroError;
+use crate::merkle;
+use crate::pda;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::decode_account::decode_account;
+use crate::utils::load_intro::load_intro;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, find_seeds};
+
+pub struct CreateReplyTreeAccounts<'a, 'info> {
+    pub introducer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_tree: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CreateReplyTreeAccounts<'a, 'info> {
+    pub fn parse(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            introducer: next_account_info(account_info_iter)?,
+            pda_intro: next_account_info(account_info_iter)?,
+            pda_tree: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `introducer` is signer
+        check_signer(ctx.introducer)?;
+        check_writable(ctx.introducer)?;
+        check_writable(ctx.pda_tree)?;
+        check_program_id(ctx.system_program, &SYSTEM_PROGRAM_ID)?;
+
+        Ok(ctx)
+    }
+}
+
+// Opt an intro into compressed replies, only its owner can create the tree
+pub fn create_reply_tree(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Creating compressed reply tree...");
+
+    let CreateReplyTreeAccounts { introducer, pda_intro, pda_tree, system_program } = CreateReplyTreeAccounts::parse(accounts)?;
+
+    let intro_data = load_intro(program_id, pda_intro)?;
+    if intro_data.introducer != *introducer.key {
+        msg!("Signer is not the intro owner");
+        return Err(IntroError::Unauthorized.into());
+    }
+
+    // Validate pda_tree is expected
+    let pda_seed = pda::reply_tree_seeds(pda_intro.key);
+    let bump_seed = find_seeds(pda_tree, &pda_seed, program_id)?;
+
+    let account_len = ReplyTree::SIZE;
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(account_len);
+
+    msg!("Creating Reply Tree PDA");
+    create_pda_account(
+        introducer,
+        pda_tree,
+        system_program,
+        program_id,
+        rent_lamports,
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+    msg!("Reply Tree PDA created: {}", pda_tree.key);
+
+    let mut tree_data = decode_account::<ReplyTree>(pda_tree)?;
+
+    if tree_data.is_initialized() {
+        msg!("Reply Tree PDA already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    tree_data.discriminator = ReplyTree::DISCRIMINATOR.to_string();
+    tree_data.is_initialized = true;
+    tree_data.intro = *pda_intro.key;
+    tree_data.leaf_count = 0;
+    tree_data.root = merkle::empty_root();
+    tree_data.filled_subtrees = [[0u8; 32]; merkle::REPLY_TREE_DEPTH];
+    tree_data.bump = bump_seed;
+
+    tree_data.serialize(&mut &mut pda_tree.data.borrow_mut()[..])?;
+    msg!("Reply Tree PDA initialized");
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use crate::state::{Config, ProposalAction};
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::load_proposal::load_proposal;
+use crate::utils::validators::check_writable;
+
+pub struct ExecuteProposalAccounts<'a, 'info> {
+    pub pda_config: &'a AccountInfo<'info>,
+    pub pda_proposal: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> ExecuteProposalAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let pda_config = next_account_info(account_info_iter)?;
+        let pda_proposal = next_account_info(account_info_iter)?;
+
+        // Anyone can execute a passed proposal, so no signer is needed
+        check_writable(pda_config)?;
+        check_writable(pda_proposal)?;
+
+        let config = load_config(program_id, pda_config)?;
+
+        Ok(Self { pda_config, pda_proposal, config })
+    }
+}
+
+// Apply a proposal's parameter change once voting has ended and it passed
+pub fn execute_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Executing proposal...");
+
+    let ExecuteProposalAccounts { pda_config, pda_proposal, mut config } = ExecuteProposalAccounts::parse(program_id, accounts)?;
+
+    let mut proposal_data = load_proposal(program_id, pda_proposal)?;
+
+    if proposal_data.executed {
+        msg!("Proposal {} was already executed", proposal_data.index);
+        return Err(IntroError::ProposalExecuted.into());
+    }
+
+    let clock = Clock::get()?;
+    if clock.slot < proposal_data.end_slot {
+        msg!("Voting ends at slot {}", proposal_data.end_slot);
+        return Err(IntroError::VotingOpen.into());
+    }
+
+    if !proposal_data.passed() {
+        msg!("Proposal {} did not pass, {} yes / {} no", proposal_data.index, proposal_data.yes_votes / LAMPORTS_PER_SOL, proposal_data.no_votes / LAMPORTS_PER_SOL);
+        return Err(IntroError::ProposalNotPassed.into());
+    }
+
+    // Checked again in case the bounds were lowered while the proposal was open
+    if let Err(error) = proposal_data.action.check_bounds() {
+        msg!("{:?} is outside the governed bounds", proposal_data.action);
+        return Err(error);
+    }
+
+    match proposal_data.action {
+        ProposalAction::SetIntroReward { amount } => config.intro_reward = Some(amount),
+        ProposalAction::SetReplyReward { amount } => config.reply_reward = Some(amount),
+        ProposalAction::SetMaxMessageBytes { max_bytes } => config.max_message_bytes = Some(max_bytes),
+    }
+    config.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+
+    proposal_data.executed = true;
+    proposal_data.serialize(&mut &mut pda_proposal.data.borrow_mut()[..])?;
+    msg!("Proposal {} executed: {:?}", proposal_data.index, proposal_data.action);
+
+    Ok(())
+}
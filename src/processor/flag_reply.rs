@@ -0,0 +1,120 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::ReplyBond;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::close_account::close_account;
+use crate::utils::load_community::load_community;
+use crate::utils::validators::{check_signer, check_writable, check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+use crate::utils::load_intro::load_intro;
+
+pub struct FlagReplyAccounts<'a, 'info> {
+    pub flagger: &'a AccountInfo<'info>,
+    pub introducer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_bond: &'a AccountInfo<'info>,
+    pub replier: &'a AccountInfo<'info>,
+    // Only needed when a community moderator flags
+    pub community: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> FlagReplyAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            flagger: next_account_info(account_info_iter)?,
+            introducer: next_account_info(account_info_iter)?,
+            pda_intro: next_account_info(account_info_iter)?,
+            pda_bond: next_account_info(account_info_iter)?,
+            replier: next_account_info(account_info_iter)?,
+            community: next_account_info(account_info_iter).ok(),
+        };
+
+        // Check that `flagger` is signer
+        check_signer(ctx.flagger)?;
+        check_writable(ctx.introducer)?;
+
+        // Validate that program owns PDAs
+        check_owner(ctx.pda_intro, program_id)?;
+        check_owner(ctx.pda_bond, program_id)?;
+        check_writable(ctx.pda_bond)?;
+        check_writable(ctx.replier)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn flag_reply(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Flagging reply as spam...");
+
+    let FlagReplyAccounts { flagger, introducer, pda_intro, pda_bond, replier, community } = FlagReplyAccounts::parse(program_id, accounts)?;
+
+    let intro_data = load_intro(program_id, pda_intro)?;
+
+    if intro_data.introducer != *introducer.key {
+        msg!("Introducer passed in does not match intro owner");
+        return Err(IntroError::Unauthorized.into());
+    }
+
+    // Only the intro owner or a moderator of its community can slash bonds posted against the intro
+    if flagger.key != introducer.key {
+        let is_moderator = match (intro_data.community, community) {
+            (Some(expected), Some(community)) => load_community(program_id, community, &expected)?.moderators.contains(flagger.key),
+            _ => false,
+        };
+        if !is_moderator {
+            msg!("Signer is neither the intro owner nor a community moderator");
+            return Err(IntroError::Unauthorized.into());
+        }
+    }
+
+    let bond_data = decode_account::<ReplyBond>(pda_bond)?;
+
+    if !bond_data.is_initialized() {
+        msg!("Reply Bond PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    // Validate pda_bond is expected
+    check_seeds(pda_bond, &pda::bond_seeds(&bond_data.reply), bond_data.bump, program_id)?;
+
+    if bond_data.intro != *pda_intro.key {
+        msg!("Reply bond does not belong to this intro");
+        return Err(IntroError::BondMismatch.into());
+    }
+
+    if bond_data.replier != *replier.key {
+        msg!("Replier passed in does not match bond replier");
+        return Err(IntroError::BondMismatch.into());
+    }
+
+    let clock = Clock::get()?;
+    if clock.slot > bond_data.challenge_end_slot {
+        msg!("Challenge window closed at slot {}", bond_data.challenge_end_slot);
+        return Err(IntroError::ChallengeWindowClosed.into());
+    }
+
+    // Slash the bond to the intro owner, the rent-exempt reserve goes back to the replier
+    let bond_lamports = pda_bond.lamports().checked_sub(bond_data.amount).ok_or(IntroError::ArithmeticOverflow)?;
+    let introducer_lamports = introducer.lamports().checked_add(bond_data.amount).ok_or(IntroError::ArithmeticOverflow)?;
+    **pda_bond.lamports.borrow_mut() = bond_lamports;
+    **introducer.lamports.borrow_mut() = introducer_lamports;
+    msg!("Slashed {} lamport bond to intro owner", bond_data.amount);
+
+    close_account(pda_bond, replier)?;
+    msg!("Returned bond account rent to replier");
+
+    Ok(())
+}
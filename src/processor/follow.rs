@@ -0,0 +1,173 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID
+};
+use borsh::BorshSerialize;
+use crate::state::{Follow, UserProfile};
+use crate::error::IntroError;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::accrue_vesting::accrue_vesting;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_owner, check_seeds, find_seeds};
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+
+pub struct FollowAccounts<'a, 'info> {
+    pub follower: &'a AccountInfo<'info>,
+    pub followee: &'a AccountInfo<'info>,
+    pub pda_follow: &'a AccountInfo<'info>,
+    pub follower_profile: &'a AccountInfo<'info>,
+    pub followee_profile: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    // Follower's vesting PDA, only passed by callers that want the first-follow reward
+    pub pda_vesting: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> FollowAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let follower = next_account_info(account_info_iter)?;
+        let followee = next_account_info(account_info_iter)?;
+        let pda_follow = next_account_info(account_info_iter)?;
+        let follower_profile = next_account_info(account_info_iter)?;
+        let followee_profile = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        // Check that `follower` is signer
+        check_signer(follower)?;
+        check_writable(follower)?;
+        check_writable(pda_follow)?;
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+
+        // Validate that program owns profile PDAs
+        check_owner(follower_profile, program_id)?;
+        check_writable(follower_profile)?;
+        check_owner(followee_profile, program_id)?;
+        check_writable(followee_profile)?;
+
+        let pda_vesting = if account_info_iter.len() > 0 {
+            Some(next_account_info(account_info_iter)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            follower,
+            followee,
+            pda_follow,
+            follower_profile,
+            followee_profile,
+            system_program,
+            pda_vesting,
+        })
+    }
+}
+
+pub fn follow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Following user...");
+
+    let FollowAccounts {
+        follower,
+        followee,
+        pda_follow,
+        follower_profile,
+        followee_profile,
+        system_program,
+        pda_vesting,
+    } = FollowAccounts::parse(program_id, accounts)?;
+
+    if follower.key == followee.key {
+        msg!("Follower and followee are the same wallet");
+        return Err(IntroError::SelfFollow.into());
+    }
+
+    let rent = Rent::get()?;
+
+    /*** CREATE FOLLOW PDA ***/
+    {
+        let pda_seed = pda::follow_seeds(follower.key, followee.key);
+
+        // Validate pda_follow is expected
+        let bump_seed = find_seeds(pda_follow, &pda_seed, program_id)?;
+
+        let account_len = Follow::SIZE;
+        let rent_lamports = rent.minimum_balance(account_len);
+
+        msg!("Creating Follow PDA");
+        create_pda_account(
+            follower,
+            pda_follow,
+            system_program,
+            program_id,
+            rent_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Follow PDA created: {}", pda_follow.key);
+
+        let mut follow_data = decode_account::<Follow>(pda_follow)?;
+
+        if follow_data.is_initialized() {
+            msg!("Follow PDA already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        follow_data.discriminator = Follow::DISCRIMINATOR.to_string();
+        follow_data.is_initialized = true;
+        follow_data.follower = *follower.key;
+        follow_data.followee = *followee.key;
+        follow_data.bump = bump_seed;
+
+        follow_data.serialize(&mut &mut pda_follow.data.borrow_mut()[..])?;
+    }
+
+    /*** UPDATE PROFILE COUNTS ***/
+    let mut follower_data = decode_account::<UserProfile>(follower_profile)?;
+    if !follower_data.is_initialized() {
+        msg!("Follower profile is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+    check_seeds(follower_profile, &pda::profile_seeds(follower.key), follower_data.bump, program_id)?;
+
+    let mut followee_data = decode_account::<UserProfile>(followee_profile)?;
+    if !followee_data.is_initialized() {
+        msg!("Followee profile is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+    check_seeds(followee_profile, &pda::profile_seeds(followee.key), followee_data.bump, program_id)?;
+
+    follower_data.following_count = follower_data.following_count.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+    followee_data.follower_count = followee_data.follower_count.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+
+    // Reward the first ever follow, only when the caller passes the vesting PDA
+    let reward_amount = LAMPORTS_PER_SOL;
+    let pda_vesting = pda_vesting.filter(|_| !follower_data.follow_reward_claimed);
+    if pda_vesting.is_some() {
+        follower_data.follow_reward_claimed = true;
+        follower_data.tokens_earned = follower_data.tokens_earned.checked_add(reward_amount).ok_or(IntroError::ArithmeticOverflow)?;
+    }
+
+    follower_data.serialize(&mut &mut follower_profile.data.borrow_mut()[..])?;
+    followee_data.serialize(&mut &mut followee_profile.data.borrow_mut()[..])?;
+    msg!("Following {} users, followee has {} followers", follower_data.following_count, followee_data.follower_count);
+
+    /*** VEST FIRST FOLLOW REWARD ***/
+    // Vests like every other reward, so it can be clawed back from throwaway wallets
+    if let Some(pda_vesting) = pda_vesting {
+        msg!("Vesting 1 token for first follow");
+        accrue_vesting(program_id, follower, follower.key, pda_vesting, system_program, &rent, reward_amount)?;
+    }
+
+    Ok(())
+}
@@ -3,61 +3,110 @@ use solana_program::{
     pubkey::Pubkey,
     msg,
     account_info::{next_account_info, AccountInfo},
-    system_instruction,
     sysvar::{rent::Rent, Sysvar},
     program::invoke_signed,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
 };
 use spl_token::{instruction::initialize_mint, ID as TOKEN_PROGRAM_ID};
-use crate::error::IntroError;
+use borsh::BorshSerialize;
+use crate::state::{Config, MintAuthorityMode};
+use crate::pda;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_signer, check_writable, find_seeds, check_program_id, check_upgrade_authority};
+use crate::utils::decode_account::decode_account;
 
-pub fn initialize_token_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
+pub struct InitializeMintAccounts<'a, 'info> {
+    pub initializer: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub mint_auth: &'a AccountInfo<'info>,
+    pub pda_config: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub sysvar_rent: &'a AccountInfo<'info>,
+    pub mint_bump: u8,
+    pub mint_auth_bump: u8,
+    pub stake_vault_bump: u8,
+    pub config_bump: u8,
+}
 
-    let initializer = next_account_info(account_info_iter)?;
-    let token_mint = next_account_info(account_info_iter)?;
-    let mint_auth = next_account_info(account_info_iter)?;
-    let system_program = next_account_info(account_info_iter)?;
-    let token_program = next_account_info(account_info_iter)?;
-    let sysvar_rent = next_account_info(account_info_iter)?;
+impl<'a, 'info> InitializeMintAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
 
-    let (mint_pda, mint_bump) = Pubkey::find_program_address(&[b"token_mint"], program_id);
-    let (mint_auth_pda, _mint_auth_bump) = Pubkey::find_program_address(&[b"token_auth"], program_id);
+        let initializer = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let mint_auth = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let sysvar_rent = next_account_info(account_info_iter)?;
+        let program_data = next_account_info(account_info_iter)?;
 
-    msg!("Token mint: {:?}", mint_pda);
-    msg!("Mint authority: {:?}", mint_auth_pda);
+        check_signer(initializer)?;
+        check_writable(initializer)?;
+        check_writable(token_mint)?;
+        check_writable(pda_config)?;
 
-    if mint_pda != *token_mint.key {
-        msg!("Incorrect token mint account");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
+        // The initializer becomes admin, so only the upgrade authority can set up the program
+        check_upgrade_authority(program_id, program_data, initializer.key)?;
 
-    if *token_program.key != TOKEN_PROGRAM_ID {
-        msg!("Incorrect token program");
-        return Err(IntroError::IncorrectAccountError.into());
-    }
+        // Only place the mint, mint authority, stake vault and config bumps are searched for, they are stored in Config
+        let mint_bump = find_seeds(token_mint, &pda::token_mint_seeds(), program_id)?;
+        let mint_auth_bump = find_seeds(mint_auth, &pda::token_auth_seeds(), program_id)?;
+        let (_, stake_vault_bump) = Pubkey::find_program_address(&pda::stake_vault_seeds(), program_id);
+        let config_bump = find_seeds(pda_config, &pda::config_seeds(), program_id)?;
 
-    if *mint_auth.key != mint_auth_pda {
-        msg!("Incorrect mint auth account");
-        return Err(IntroError::IncorrectAccountError.into());
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+
+        Ok(Self {
+            initializer,
+            token_mint,
+            mint_auth,
+            pda_config,
+            system_program,
+            token_program,
+            sysvar_rent,
+            mint_bump,
+            mint_auth_bump,
+            stake_vault_bump,
+            config_bump,
+        })
     }
+}
+
+pub fn initialize_token_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let InitializeMintAccounts {
+        initializer,
+        token_mint,
+        mint_auth,
+        pda_config,
+        system_program,
+        token_program,
+        sysvar_rent,
+        mint_bump,
+        mint_auth_bump,
+        stake_vault_bump,
+        config_bump,
+    } = InitializeMintAccounts::parse(program_id, accounts)?;
+
+    msg!("Token mint: {:?}", token_mint.key);
+    msg!("Mint authority: {:?}", mint_auth.key);
 
     let rent = Rent::get()?;
     let rent_lamports = rent.minimum_balance(82);
 
-    invoke_signed(
-        &system_instruction::create_account(
-            initializer.key,
-            token_mint.key,
-            rent_lamports,
-            82,
-            token_program.key,
-        ),
-        &[
-            initializer.clone(),
-            token_mint.clone(),
-            system_program.clone(),
-        ],
-        &[&[b"token_mint", &[mint_bump]]],
+    create_pda_account(
+        initializer,
+        token_mint,
+        system_program,
+        token_program.key,
+        rent_lamports,
+        82,
+        &pda::token_mint_seeds(),
+        mint_bump
     )?;
 
     msg!("Created token mint account");
@@ -68,14 +117,53 @@ pub fn initialize_token_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
             token_program.key,
             token_mint.key,
             mint_auth.key,
-            Option::None,
+            // Freeze authority starts with the program so the admin can hand it over later
+            Some(mint_auth.key),
             9,
         )?,
         &[token_mint.clone(), sysvar_rent.clone(), mint_auth.clone()],
-        &[&[b"token_mint", &[mint_bump]]],
+        &[&[pda::TOKEN_MINT_SEED, &[mint_bump]]],
     )?;
 
     msg!("Initialized token mint");
 
+    /*** CREATE CONFIG PDA ***/
+    create_pda_account(
+        initializer,
+        pda_config,
+        system_program,
+        program_id,
+        rent.minimum_balance(Config::SIZE),
+        Config::SIZE,
+        &pda::config_seeds(),
+        config_bump
+    )?;
+
+    let mut config_data = decode_account::<Config>(pda_config)?;
+
+    if config_data.is_initialized() {
+        msg!("Config PDA already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    config_data.discriminator = Config::DISCRIMINATOR.to_string();
+    config_data.is_initialized = true;
+    config_data.admin = *initializer.key;
+    config_data.mint_bump = mint_bump;
+    config_data.mint_auth_bump = mint_auth_bump;
+    config_data.stake_vault_bump = stake_vault_bump;
+    config_data.bump = config_bump;
+    config_data.referral_reward = Config::DEFAULT_REFERRAL_REWARD;
+    config_data.max_supply = Config::DEFAULT_MAX_SUPPLY;
+    config_data.total_minted = 0;
+    config_data.mint_authority_mode = MintAuthorityMode::Program;
+    config_data.intro_reward = None;
+    config_data.reply_reward = None;
+    config_data.max_message_bytes = None;
+    config_data.proposal_count = 0;
+
+    config_data.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+    msg!("Config PDA initialized, admin {}", initializer.key);
+
     Ok(())
-}
\ No newline at end of file
+}
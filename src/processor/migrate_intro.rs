@@ -0,0 +1,196 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use borsh::BorshSerialize;
+use crate::state::{IntroAccountState, IntroReplyCounter, LegacyIntroAccountState, LegacyIntroReplyCounter};
+use crate::error::IntroError;
+use crate::utils::close_account::close_account;
+use crate::utils::next_intro_index::next_intro_index;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::pda;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_owner, find_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct MigrateIntroAccounts<'a, 'info> {
+    pub initializer: &'a AccountInfo<'info>,
+    pub pda_legacy_intro: &'a AccountInfo<'info>,
+    pub pda_legacy_counter: &'a AccountInfo<'info>,
+    pub pda_intro_counter: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_counter: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MigrateIntroAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            initializer: next_account_info(account_info_iter)?,
+            pda_legacy_intro: next_account_info(account_info_iter)?,
+            pda_legacy_counter: next_account_info(account_info_iter)?,
+            pda_intro_counter: next_account_info(account_info_iter)?,
+            pda_intro: next_account_info(account_info_iter)?,
+            pda_counter: next_account_info(account_info_iter)?,
+            system_program: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `initializer` is signer
+        check_signer(ctx.initializer)?;
+        check_writable(ctx.initializer)?;
+        check_program_id(ctx.system_program, &SYSTEM_PROGRAM_ID)?;
+
+        // Validate that program owns legacy PDAs
+        check_owner(ctx.pda_legacy_intro, program_id)?;
+        check_writable(ctx.pda_legacy_intro)?;
+        check_owner(ctx.pda_legacy_counter, program_id)?;
+        check_writable(ctx.pda_legacy_counter)?;
+        check_writable(ctx.pda_intro_counter)?;
+        check_writable(ctx.pda_intro)?;
+        check_writable(ctx.pda_counter)?;
+
+        // Legacy accounts predate stored bumps, so they are searched for
+        find_seeds(ctx.pda_legacy_intro, &pda::legacy_intro_seeds(ctx.initializer.key), program_id)?;
+        find_seeds(ctx.pda_legacy_counter, &pda::legacy_reply_counter_seeds(ctx.initializer.key), program_id)?;
+
+        Ok(ctx)
+    }
+}
+
+// Move a legacy single intro, seeded by [initializer] with its reply counter at [initializer, "reply"],
+// into the index-seeded scheme. Replies already posted keep pointing at the legacy intro address.
+pub fn migrate_intro(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Migrating legacy introduction...");
+
+    let MigrateIntroAccounts {
+        initializer,
+        pda_legacy_intro,
+        pda_legacy_counter,
+        pda_intro_counter,
+        pda_intro,
+        pda_counter,
+        system_program,
+    } = MigrateIntroAccounts::parse(program_id, accounts)?;
+
+    let legacy_intro = decode_account::<LegacyIntroAccountState>(pda_legacy_intro)?;
+    if !legacy_intro.is_initialized() || legacy_intro.discriminator != IntroAccountState::DISCRIMINATOR {
+        msg!("Legacy Intro PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    let legacy_counter = decode_account::<LegacyIntroReplyCounter>(pda_legacy_counter)?;
+    if !legacy_counter.is_initialized() {
+        msg!("Legacy Reply Counter PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    let rent = Rent::get()?;
+
+    /*** CLAIM NEXT INTRO INDEX ***/
+    let index = next_intro_index(program_id, initializer, pda_intro_counter, system_program, &rent)?;
+    let index_bytes = index.to_be_bytes();
+
+    /*** CREATE INTRO PDA ***/
+    {
+        let pda_seed = pda::intro_seeds(&None, initializer.key, &index_bytes);
+
+        // Validate pda_intro is expected
+        let bump_seed = find_seeds(pda_intro, &pda_seed, program_id)?;
+
+        // Size from the contents, since a full legacy intro has no room left for the newer fields
+        let account_len = IntroAccountState::get_account_size(legacy_intro.name.clone(), legacy_intro.message.clone()).max(1000);
+        let rent_lamports = rent.minimum_balance(account_len);
+
+        msg!("Creating Intro PDA");
+        create_pda_account(
+            initializer,
+            pda_intro,
+            system_program,
+            program_id,
+            rent_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Intro PDA created: {}", pda_intro.key);
+
+        let mut account_data = decode_account::<IntroAccountState>(pda_intro)?;
+
+        if account_data.is_initialized() {
+            msg!("Intro PDA already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        account_data.discriminator = IntroAccountState::DISCRIMINATOR.to_string();
+        account_data.is_initialized = true;
+        account_data.introducer = legacy_intro.introducer;
+        account_data.name = legacy_intro.name;
+        account_data.message = legacy_intro.message;
+        account_data.index = index;
+        account_data.community = None;
+        account_data.bump = bump_seed;
+        account_data.content_hash = None;
+        account_data.boost_score = 0;
+        account_data.boost_slot = 0;
+
+        account_data.serialize(&mut &mut pda_intro.data.borrow_mut()[..])?;
+        msg!("Intro PDA initialized");
+    }
+
+    /*** CREATE REPLY COUNTER PDA ***/
+    {
+        let pda_seed = pda::reply_counter_seeds(pda_intro.key);
+
+        // Validate pda_counter is expected
+        let bump_seed = find_seeds(pda_counter, &pda_seed, program_id)?;
+
+        let account_len = IntroReplyCounter::SIZE;
+        let rent_lamports = rent.minimum_balance(account_len);
+
+        msg!("Creating Reply Counter PDA");
+        create_pda_account(
+            initializer,
+            pda_counter,
+            system_program,
+            program_id,
+            rent_lamports,
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Reply Counter PDA created: {}", pda_counter.key);
+
+        let mut account_data = decode_account::<IntroReplyCounter>(pda_counter)?;
+
+        if account_data.is_initialized() {
+            msg!("Reply Counter PDA already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Carry the count over so reply numbering continues from the legacy intro
+        account_data.discriminator = IntroReplyCounter::DISCRIMINATOR.to_string();
+        account_data.is_initialized = true;
+        account_data.counter = legacy_counter.counter;
+        account_data.bump = bump_seed;
+
+        account_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
+        msg!("Reply Counter PDA initialized at {}", account_data.counter);
+    }
+
+    /*** CLOSE LEGACY PDAS ***/
+    close_account(pda_legacy_intro, initializer)?;
+    close_account(pda_legacy_counter, initializer)?;
+    msg!("Legacy intro migrated to index {}", index);
+
+    Ok(())
+}
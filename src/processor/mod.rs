@@ -9,11 +9,67 @@ mod add_intro;
 mod update_intro;
 mod add_reply;
 mod initialize_token_mint;
+mod release_reply_bond;
+mod flag_reply;
+mod create_profile;
+mod update_profile;
+mod follow;
+mod unfollow;
+mod migrate_intro;
+mod create_community;
+mod create_reply_tree;
+mod add_compressed_reply;
+mod add_replies;
+mod claim_vested;
+mod claw_back_vesting;
+mod stake;
+mod unstake;
+mod withdraw_stake;
+mod boost_intro;
+mod create_bounty;
+mod award_bounty;
+mod cancel_bounty;
+mod update_config;
+mod airdrop;
+mod set_freeze_authority;
+mod transfer_mint_authority;
+mod revoke_mint_authority;
+mod create_proposal;
+mod cast_vote;
+mod execute_proposal;
 
 use add_intro::add_intro;
 use update_intro::update_intro;
 use add_reply::add_reply;
 use initialize_token_mint::initialize_token_mint;
+use release_reply_bond::release_reply_bond;
+use flag_reply::flag_reply;
+use create_profile::create_profile;
+use update_profile::update_profile;
+use follow::follow;
+use unfollow::unfollow;
+use migrate_intro::migrate_intro;
+use create_community::create_community;
+use create_reply_tree::create_reply_tree;
+use add_compressed_reply::add_compressed_reply;
+use add_replies::add_replies;
+use claim_vested::claim_vested;
+use claw_back_vesting::claw_back_vesting;
+use stake::stake;
+use unstake::unstake;
+use withdraw_stake::withdraw_stake;
+use boost_intro::boost_intro;
+use create_bounty::create_bounty;
+use award_bounty::award_bounty;
+use cancel_bounty::cancel_bounty;
+use update_config::update_config;
+use airdrop::airdrop;
+use set_freeze_authority::set_freeze_authority;
+use transfer_mint_authority::transfer_mint_authority;
+use revoke_mint_authority::revoke_mint_authority;
+use create_proposal::create_proposal;
+use cast_vote::cast_vote;
+use execute_proposal::execute_proposal;
 
 pub fn process_instruction(
     program_id: &Pubkey,
@@ -22,17 +78,107 @@ pub fn process_instruction(
 ) -> ProgramResult {
     let instruction = IntroInstruction::unpack(instruction_data)?;
     match instruction {
-        IntroInstruction::AddIntro { name, message } => {
-            add_intro(program_id, accounts, name, message)
+        IntroInstruction::AddIntro { name, message, community, referrer } => {
+            add_intro(program_id, accounts, name, message, community, None, referrer)
         },
-        IntroInstruction::UpdateIntro { name, message } => {
-            update_intro(program_id, accounts, name, message)
+        IntroInstruction::UpdateIntro { index, name, message } => {
+            update_intro(program_id, accounts, index, name, message)
         },
         IntroInstruction::AddReply { reply } => {
-            add_reply(program_id, accounts, reply)
+            add_reply(program_id, accounts, reply, None)
         },
         IntroInstruction::InitializeMint => {
             initialize_token_mint(program_id, accounts)
+        },
+        IntroInstruction::ReleaseReplyBond => {
+            release_reply_bond(program_id, accounts)
+        },
+        IntroInstruction::FlagReply => {
+            flag_reply(program_id, accounts)
+        },
+        IntroInstruction::CreateProfile { display_name, avatar_uri, bio } => {
+            create_profile(program_id, accounts, display_name, avatar_uri, bio)
+        },
+        IntroInstruction::UpdateProfile { display_name, avatar_uri, bio } => {
+            update_profile(program_id, accounts, display_name, avatar_uri, bio)
+        },
+        IntroInstruction::Follow => {
+            follow(program_id, accounts)
+        },
+        IntroInstruction::Unfollow => {
+            unfollow(program_id, accounts)
+        },
+        IntroInstruction::MigrateIntro => {
+            migrate_intro(program_id, accounts)
+        },
+        IntroInstruction::CreateCommunity { name, description, moderators, reward_multiplier } => {
+            create_community(program_id, accounts, name, description, moderators, reward_multiplier)
+        },
+        IntroInstruction::AddIntroOffchain { name, content_uri, content_hash, community, referrer } => {
+            add_intro(program_id, accounts, name, content_uri, community, Some(content_hash), referrer)
+        },
+        IntroInstruction::AddReplyOffchain { content_uri, content_hash } => {
+            add_reply(program_id, accounts, content_uri, Some(content_hash))
+        },
+        IntroInstruction::CreateReplyTree => {
+            create_reply_tree(program_id, accounts)
+        },
+        IntroInstruction::AddCompressedReply { reply } => {
+            add_compressed_reply(program_id, accounts, reply)
+        },
+        IntroInstruction::AddReplies { replies } => {
+            add_replies(program_id, accounts, replies)
+        },
+        IntroInstruction::ClaimVested => {
+            claim_vested(program_id, accounts)
+        },
+        IntroInstruction::ClawBackVesting => {
+            claw_back_vesting(program_id, accounts)
+        },
+        IntroInstruction::Stake { amount } => {
+            stake(program_id, accounts, amount)
+        },
+        IntroInstruction::Unstake { amount } => {
+            unstake(program_id, accounts, amount)
+        },
+        IntroInstruction::WithdrawStake => {
+            withdraw_stake(program_id, accounts)
+        },
+        IntroInstruction::BoostIntro { amount } => {
+            boost_intro(program_id, accounts, amount)
+        },
+        IntroInstruction::CreateBounty { amount, expiry_slot } => {
+            create_bounty(program_id, accounts, amount, expiry_slot)
+        },
+        IntroInstruction::AwardBounty => {
+            award_bounty(program_id, accounts)
+        },
+        IntroInstruction::CancelBounty => {
+            cancel_bounty(program_id, accounts)
+        },
+        IntroInstruction::UpdateConfig { referral_reward, max_supply } => {
+            update_config(program_id, accounts, referral_reward, max_supply)
+        },
+        IntroInstruction::Airdrop { amounts } => {
+            airdrop(program_id, accounts, amounts)
+        },
+        IntroInstruction::SetFreezeAuthority { new_authority } => {
+            set_freeze_authority(program_id, accounts, new_authority)
+        },
+        IntroInstruction::TransferMintAuthority { new_authority } => {
+            transfer_mint_authority(program_id, accounts, new_authority)
+        },
+        IntroInstruction::RevokeMintAuthority => {
+            revoke_mint_authority(program_id, accounts)
+        },
+        IntroInstruction::CreateProposal { action } => {
+            create_proposal(program_id, accounts, action)
+        },
+        IntroInstruction::CastVote { approve } => {
+            cast_vote(program_id, accounts, approve)
+        },
+        IntroInstruction::ExecuteProposal => {
+            execute_proposal(program_id, accounts)
         }
     }
 }
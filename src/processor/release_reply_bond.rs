@@ -0,0 +1,73 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::ReplyBond;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::close_account::close_account;
+use crate::utils::validators::{check_writable, check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct ReleaseReplyBondAccounts<'a, 'info> {
+    pub replier: &'a AccountInfo<'info>,
+    pub pda_bond: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ReleaseReplyBondAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        // Anyone can crank the release, the lamports always go back to the original replier
+        let ctx = Self {
+            replier: next_account_info(account_info_iter)?,
+            pda_bond: next_account_info(account_info_iter)?,
+        };
+
+        check_writable(ctx.replier)?;
+        check_owner(ctx.pda_bond, program_id)?;
+        check_writable(ctx.pda_bond)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn release_reply_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Releasing reply bond...");
+
+    let ReleaseReplyBondAccounts { replier, pda_bond } = ReleaseReplyBondAccounts::parse(program_id, accounts)?;
+
+    let bond_data = decode_account::<ReplyBond>(pda_bond)?;
+
+    if !bond_data.is_initialized() {
+        msg!("Reply Bond PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    // Validate pda_bond is expected
+    check_seeds(pda_bond, &pda::bond_seeds(&bond_data.reply), bond_data.bump, program_id)?;
+
+    if bond_data.replier != *replier.key {
+        msg!("Replier passed in does not match bond replier");
+        return Err(IntroError::BondMismatch.into());
+    }
+
+    let clock = Clock::get()?;
+    if clock.slot <= bond_data.challenge_end_slot {
+        msg!("Challenge window open until slot {}", bond_data.challenge_end_slot);
+        return Err(IntroError::ChallengeWindowOpen.into());
+    }
+
+    close_account(pda_bond, replier)?;
+    msg!("Returned {} lamport bond to replier", bond_data.amount);
+
+    Ok(())
+}
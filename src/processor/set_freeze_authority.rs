@@ -0,0 +1,92 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program::invoke_signed,
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+};
+use spl_token::{instruction::{set_authority, AuthorityType}, state::Mint, ID as TOKEN_PROGRAM_ID};
+use crate::state::Config;
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds};
+use crate::pda;
+
+pub struct SetFreezeAuthorityAccounts<'a, 'info> {
+    pub token_mint: &'a AccountInfo<'info>,
+    pub mint_auth: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> SetFreezeAuthorityAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let mint_auth = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+
+        check_signer(admin)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_writable(token_mint)?;
+        check_seeds(mint_auth, &pda::token_auth_seeds(), config.mint_auth_bump, program_id)?;
+
+        Ok(Self { token_mint, mint_auth, token_program, config })
+    }
+}
+
+// Move the freeze authority from the token_auth PDA to `new_authority`, or disable freezing with None
+// Once moved, further rotation is done by the new authority directly through the token program
+pub fn set_freeze_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Option<Pubkey>
+) -> ProgramResult {
+    msg!("Setting freeze authority...");
+
+    let SetFreezeAuthorityAccounts { token_mint, mint_auth, token_program, config } = SetFreezeAuthorityAccounts::parse(program_id, accounts)?;
+
+    // Mints created before the program kept the freeze authority have none, and one cannot be added later
+    let mint_data = Mint::unpack(&token_mint.data.borrow())?;
+    if mint_data.freeze_authority != COption::Some(*mint_auth.key) {
+        msg!("Freeze authority is {:?}", mint_data.freeze_authority);
+        return Err(IntroError::FreezeAuthorityUnavailable.into());
+    }
+
+    invoke_signed(
+        // Instruction
+        &set_authority(
+            token_program.key,
+            token_mint.key,
+            new_authority.as_ref(),
+            AuthorityType::FreezeAccount,
+            mint_auth.key,
+            &[],
+        )?,
+        // Account_infos
+        &[token_mint.clone(), mint_auth.clone()],
+        // Seeds
+        &[&[pda::TOKEN_AUTH_SEED, &[config.mint_auth_bump]]],
+    )?;
+
+    match new_authority {
+        Some(authority) => msg!("Freeze authority set to {}", authority),
+        None => msg!("Freeze authority disabled"),
+    }
+
+    Ok(())
+}
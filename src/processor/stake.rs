@@ -0,0 +1,173 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{rent::Rent, Sysvar},
+    program::invoke,
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::{instruction::create_associated_token_account, ID as ASSOCIATED_TOKEN_PROGRAM_ID};
+use borsh::BorshSerialize;
+use crate::state::StakeAccount;
+use crate::error::IntroError;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::load_config::load_config;
+use crate::utils::load_stake::load_stake;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_ata, check_stake_vault_accounts, find_seeds};
+use crate::pda;
+
+pub struct StakeAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub user_ata: &'a AccountInfo<'info>,
+    pub vault_auth: &'a AccountInfo<'info>,
+    pub vault_ata: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> StakeAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let wallet = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let user_ata = next_account_info(account_info_iter)?;
+        let vault_auth = next_account_info(account_info_iter)?;
+        let vault_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(wallet)?;
+        check_writable(wallet)?;
+        check_writable(pda_stake)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        check_stake_vault_accounts(program_id, &config, token_mint, vault_auth, vault_ata, token_program)?;
+        check_ata(user_ata, wallet.key, token_mint.key)?;
+        check_writable(user_ata)?;
+
+        Ok(Self {
+            wallet,
+            pda_stake,
+            user_ata,
+            vault_auth,
+            vault_ata,
+            token_mint,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+// Move reward tokens from the wallet into the stake vault
+pub fn stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64
+) -> ProgramResult {
+    msg!("Staking {} tokens...", amount / LAMPORTS_PER_SOL);
+
+    let StakeAccounts {
+        wallet,
+        pda_stake,
+        user_ata,
+        vault_auth,
+        vault_ata,
+        token_mint,
+        system_program,
+        token_program,
+        associated_token_program,
+    } = StakeAccounts::parse(program_id, accounts)?;
+
+    if amount == 0 {
+        msg!("Stake amount must be positive");
+        return Err(IntroError::ZeroAmount.into());
+    }
+
+    /*** LOAD OR CREATE STAKE PDA ***/
+    let mut stake_data = if !is_account_initialized(pda_stake) {
+        // Validate pda_stake is expected
+        let pda_seed = pda::stake_seeds(wallet.key);
+        let bump_seed = find_seeds(pda_stake, &pda_seed, program_id)?;
+
+        let account_len = StakeAccount::SIZE;
+        let rent = Rent::get()?;
+
+        msg!("Creating Stake PDA");
+        create_pda_account(
+            wallet,
+            pda_stake,
+            system_program,
+            program_id,
+            rent.minimum_balance(account_len),
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Stake PDA created: {}", pda_stake.key);
+
+        StakeAccount {
+            discriminator: StakeAccount::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            wallet: *wallet.key,
+            amount: 0,
+            unbonding_amount: 0,
+            unbonding_end_slot: 0,
+            bump: bump_seed
+        }
+    } else {
+        load_stake(program_id, pda_stake, wallet.key)?
+    };
+
+    /*** CREATE VAULT ATA ON FIRST STAKE ***/
+    if !is_account_initialized(vault_ata) {
+        msg!("Vault ATA not created, creating...");
+        let instruction = create_associated_token_account(wallet.key, vault_auth.key, token_mint.key, token_program.key);
+        invoke(
+            &instruction,
+            &[
+                wallet.clone(),
+                vault_ata.clone(),
+                vault_auth.clone(),
+                token_mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                associated_token_program.clone(),
+            ]
+        )?;
+        msg!("Created vault ATA");
+    }
+
+    /*** TRANSFER TO VAULT ***/
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            user_ata.key,
+            vault_ata.key,
+            wallet.key,
+            &[],
+            amount,
+        )?,
+        &[user_ata.clone(), vault_ata.clone(), wallet.clone()],
+    )?;
+
+    stake_data.amount = stake_data.amount.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+    stake_data.serialize(&mut &mut pda_stake.data.borrow_mut()[..])?;
+    msg!("Staked {} tokens in total, tier {}", stake_data.amount / LAMPORTS_PER_SOL, stake_data.tier());
+
+    Ok(())
+}
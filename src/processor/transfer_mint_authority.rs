@@ -0,0 +1,95 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program::invoke_signed,
+    program_error::ProgramError,
+};
+use spl_token::{instruction::{set_authority, AuthorityType}, ID as TOKEN_PROGRAM_ID};
+use borsh::BorshSerialize;
+use crate::state::{Config, MintAuthorityMode};
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_seeds};
+use crate::pda;
+
+pub struct TransferMintAuthorityAccounts<'a, 'info> {
+    pub pda_config: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub mint_auth: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> TransferMintAuthorityAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let mint_auth = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+
+        check_signer(admin)?;
+        check_writable(pda_config)?;
+        check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+        check_writable(token_mint)?;
+        check_seeds(mint_auth, &pda::token_auth_seeds(), config.mint_auth_bump, program_id)?;
+
+        Ok(Self { pda_config, token_mint, mint_auth, token_program, config })
+    }
+}
+
+// Hand the mint authority to a multisig or governance program, reward mints in this program fail from then on
+pub fn transfer_mint_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Pubkey
+) -> ProgramResult {
+    msg!("Transferring mint authority to {}...", new_authority);
+
+    let TransferMintAuthorityAccounts {
+        pda_config,
+        token_mint,
+        mint_auth,
+        token_program,
+        mut config,
+    } = TransferMintAuthorityAccounts::parse(program_id, accounts)?;
+
+    if config.mint_authority_mode != MintAuthorityMode::Program {
+        msg!("Mint authority is already {:?}", config.mint_authority_mode);
+        return Err(IntroError::MintAuthorityUnavailable.into());
+    }
+
+    invoke_signed(
+        // Instruction
+        &set_authority(
+            token_program.key,
+            token_mint.key,
+            Some(&new_authority),
+            AuthorityType::MintTokens,
+            mint_auth.key,
+            &[],
+        )?,
+        // Account_infos
+        &[token_mint.clone(), mint_auth.clone()],
+        // Seeds
+        &[&[pda::TOKEN_AUTH_SEED, &[config.mint_auth_bump]]],
+    )?;
+
+    config.mint_authority_mode = MintAuthorityMode::External { authority: new_authority };
+    config.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+    msg!("Mint authority transferred to {}", new_authority);
+
+    Ok(())
+}
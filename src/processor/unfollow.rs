@@ -0,0 +1,92 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use borsh::BorshSerialize;
+use crate::state::{Follow, UserProfile};
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::close_account::close_account;
+use crate::utils::validators::{check_signer, check_writable, check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct UnfollowAccounts<'a, 'info> {
+    pub follower: &'a AccountInfo<'info>,
+    pub followee: &'a AccountInfo<'info>,
+    pub pda_follow: &'a AccountInfo<'info>,
+    pub follower_profile: &'a AccountInfo<'info>,
+    pub followee_profile: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> UnfollowAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            follower: next_account_info(account_info_iter)?,
+            followee: next_account_info(account_info_iter)?,
+            pda_follow: next_account_info(account_info_iter)?,
+            follower_profile: next_account_info(account_info_iter)?,
+            followee_profile: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `follower` is signer
+        check_signer(ctx.follower)?;
+        check_writable(ctx.follower)?;
+
+        // Validate that program owns PDAs
+        check_owner(ctx.pda_follow, program_id)?;
+        check_writable(ctx.pda_follow)?;
+        check_owner(ctx.follower_profile, program_id)?;
+        check_writable(ctx.follower_profile)?;
+        check_owner(ctx.followee_profile, program_id)?;
+        check_writable(ctx.followee_profile)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn unfollow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Unfollowing user...");
+
+    let UnfollowAccounts {
+        follower,
+        followee,
+        pda_follow,
+        follower_profile,
+        followee_profile,
+    } = UnfollowAccounts::parse(program_id, accounts)?;
+
+    let follow_data = decode_account::<Follow>(pda_follow)?;
+    if !follow_data.is_initialized() {
+        msg!("Follow PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+    check_seeds(pda_follow, &pda::follow_seeds(follower.key, followee.key), follow_data.bump, program_id)?;
+
+    let mut follower_data = decode_account::<UserProfile>(follower_profile)?;
+    check_seeds(follower_profile, &pda::profile_seeds(follower.key), follower_data.bump, program_id)?;
+
+    let mut followee_data = decode_account::<UserProfile>(followee_profile)?;
+    check_seeds(followee_profile, &pda::profile_seeds(followee.key), followee_data.bump, program_id)?;
+
+    // A Follow PDA exists for this pair, so both counts are at least 1
+    follower_data.following_count = follower_data.following_count.checked_sub(1).ok_or(IntroError::ArithmeticOverflow)?;
+    followee_data.follower_count = followee_data.follower_count.checked_sub(1).ok_or(IntroError::ArithmeticOverflow)?;
+
+    follower_data.serialize(&mut &mut follower_profile.data.borrow_mut()[..])?;
+    followee_data.serialize(&mut &mut followee_profile.data.borrow_mut()[..])?;
+
+    // Refund Follow PDA rent to the follower
+    close_account(pda_follow, follower)?;
+    msg!("Unfollowed {}", followee.key);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use crate::state::StakeAccount;
+use crate::error::IntroError;
+use crate::utils::load_stake::load_stake;
+use crate::utils::validators::{check_signer, check_writable};
+
+pub struct UnstakeAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> UnstakeAccounts<'a, 'info> {
+    pub fn parse(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            wallet: next_account_info(account_info_iter)?,
+            pda_stake: next_account_info(account_info_iter)?,
+        };
+
+        check_signer(ctx.wallet)?;
+        check_writable(ctx.pda_stake)?;
+
+        Ok(ctx)
+    }
+}
+
+// Start unbonding `amount`, the tier drops right away and the tokens can be withdrawn once unbonding ends
+pub fn unstake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64
+) -> ProgramResult {
+    msg!("Unstaking {} tokens...", amount / LAMPORTS_PER_SOL);
+
+    let UnstakeAccounts { wallet, pda_stake } = UnstakeAccounts::parse(accounts)?;
+
+    let mut stake_data = load_stake(program_id, pda_stake, wallet.key)?;
+
+    if amount == 0 {
+        msg!("Unstake amount must be positive");
+        return Err(IntroError::ZeroAmount.into());
+    }
+
+    if amount > stake_data.amount {
+        msg!("Only {} tokens staked", stake_data.amount / LAMPORTS_PER_SOL);
+        return Err(IntroError::InsufficientStake.into());
+    }
+
+    // Each unstake restarts the unbonding period for everything still unbonding
+    let clock = Clock::get()?;
+    stake_data.amount = stake_data.amount.checked_sub(amount).ok_or(IntroError::ArithmeticOverflow)?;
+    stake_data.unbonding_amount = stake_data.unbonding_amount.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+    stake_data.unbonding_end_slot = clock.slot
+        .checked_add(StakeAccount::UNBONDING_SLOTS)
+        .ok_or(IntroError::ArithmeticOverflow)?;
+
+    stake_data.serialize(&mut &mut pda_stake.data.borrow_mut()[..])?;
+    msg!("{} tokens unbonding until slot {}, tier {}", stake_data.unbonding_amount / LAMPORTS_PER_SOL, stake_data.unbonding_end_slot, stake_data.tier());
+
+    Ok(())
+}
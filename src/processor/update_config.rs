@@ -0,0 +1,64 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use crate::state::Config;
+use crate::error::IntroError;
+use crate::utils::load_config::load_config;
+use crate::utils::validators::{check_signer, check_writable};
+
+pub struct UpdateConfigAccounts<'a, 'info> {
+    pub pda_config: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> UpdateConfigAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let admin = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(admin)?;
+        check_writable(pda_config)?;
+
+        let config = load_config(program_id, pda_config)?;
+        if config.admin != *admin.key {
+            msg!("Signer is not the program admin");
+            return Err(IntroError::Unauthorized.into());
+        }
+
+        Ok(Self { pda_config, config })
+    }
+}
+
+// Admin-only update of tunable Config values
+pub fn update_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    referral_reward: u64,
+    max_supply: u64
+) -> ProgramResult {
+    msg!("Updating config...");
+
+    let UpdateConfigAccounts { pda_config, mut config } = UpdateConfigAccounts::parse(program_id, accounts)?;
+
+    // A cap below what is already minted would block every future mint
+    if max_supply != 0 && max_supply < config.total_minted {
+        msg!("Max supply is below the {} tokens already minted", config.total_minted / LAMPORTS_PER_SOL);
+        return Err(IntroError::MaxSupplyBelowMinted.into());
+    }
+
+    config.referral_reward = referral_reward;
+    config.max_supply = max_supply;
+
+    config.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+    msg!("Referral reward set to {} tokens, max supply to {} tokens", referral_reward / LAMPORTS_PER_SOL, max_supply / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
@@ -4,92 +4,104 @@ use solana_program::{
     msg,
     account_info::{next_account_info, AccountInfo},
     program_error::ProgramError,
-    program_pack::IsInitialized,
-    borsh0_10::try_from_slice_unchecked,
 };
 use borsh::BorshSerialize;
-use crate::state::IntroAccountState;
+use crate::state::{Config, IntroAccountState};
 use crate::error::IntroError;
+use crate::validation::{validate_text, NAME_LIMITS, MESSAGE_LIMITS};
+use crate::utils::validators::{check_signer, check_writable, check_owner};
+use crate::utils::load_intro::load_intro;
+use crate::utils::load_stake_tier::load_stake_tier;
+use crate::utils::load_config::load_config;
+
+pub struct UpdateIntroAccounts<'a, 'info> {
+    pub initializer: &'a AccountInfo<'info>,
+    pub pda_intro: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub config: Config,
+}
+
+impl<'a, 'info> UpdateIntroAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let initializer = next_account_info(account_info_iter)?;
+        let pda_intro = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        // No msg.sender global variable available in Solana, and fee-payer is not available here either
+        // So convention is to send initiating address as the first provided account => Check this is signer
+        check_signer(initializer)?;
+        check_owner(pda_intro, program_id)?;
+        check_writable(pda_intro)?;
+
+        // Message limits are governed through Config
+        let config = load_config(program_id, pda_config)?;
+
+        Ok(Self { initializer, pda_intro, pda_stake, config })
+    }
+}
 
 pub fn update_intro(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    index: u64,
     name: String,
     message: String
 ) -> ProgramResult {
-    msg!("Updating introduction...");
+    msg!("Updating introduction {}...", index);
     msg!("Name: {}", name);
     msg!("Message: {}", message);
     
-    // Get Account iterator
-    let account_info_iter = &mut accounts.iter();
-    
-    // Get accounts
-    let initializer = next_account_info(account_info_iter)?;
-    let pda_intro = next_account_info(account_info_iter)?;
+    let UpdateIntroAccounts { initializer, pda_intro, pda_stake, config } = UpdateIntroAccounts::parse(program_id, accounts)?;
 
-    // Validate that program owns PDA
-    if pda_intro.owner != program_id {
-        return Err(ProgramError::IllegalOwner)
-    }
+    // Load account data, its address is checked against the stored introducer, index and community
+    msg!("unpacking state account");
+    let mut account_data = load_intro(program_id, pda_intro)?;
+    msg!("borrowed account data");
 
-    // Check that `initializer` is signer
-    // No msg.sender global variable available in Solana, and fee-payer is not available here either
-    // So convention is to send initiating address as the first provided account => Check this is signer
-    if !initializer.is_signer {
-        msg!("Missing required signature");
-        return Err(ProgramError::MissingRequiredSignature);
+    if account_data.introducer != *initializer.key {
+        msg!("Signer is not the intro owner");
+        return Err(IntroError::Unauthorized.into());
     }
 
-    // Get PDA
-    let pda_seed = &[initializer.key.as_ref()];
-    let (pda, _bump_seed) = Pubkey::find_program_address(pda_seed, program_id);
-
-    // Validate pda_intro is expected
-    if pda != *pda_intro.key {
-        msg!("Invalid seeds for PDA");
-        return Err(IntroError::InvalidPDA.into())
+    if account_data.index != index {
+        msg!("Intro {} is not at index {}", pda_intro.key, index);
+        return Err(IntroError::IntroAddressMismatch.into());
     }
 
-    // Ensure new_account_len <= 1000 bytes
-    let new_account_len: usize = 1 + (4 + name.len()) + (4 + message.len());
-    if new_account_len > 1000 {
-        msg!("Data length is larger than 1000 bytes");
-        return Err(IntroError::InvalidDataLength.into())  
-    };
+    // Same governed and stake tier limits as AddIntro, the account only holds the extra bytes of the tier it was created at
+    let tier = load_stake_tier(program_id, pda_stake, initializer.key)?;
+    validate_text(&name, &NAME_LIMITS)?;
+    validate_text(&message, &config.message_limits(MESSAGE_LIMITS).for_stake_tier(tier))?;
 
-    // Ensure data length <1000 bytes
-    let account_len = 1000;
+    // Ensure the updated intro fits the existing account
+    let account_len = pda_intro.data_len();
     if IntroAccountState::get_account_size(name.clone(), message.clone()) > account_len {
-        msg!("Data length is larger than 1000 bytes");
+        msg!("Data length is larger than {} bytes", account_len);
         return Err(IntroError::InvalidDataLength.into())  
     }
 
-    // Update account data
-    msg!("unpacking state account");
-    let mut account_data = try_from_slice_unchecked::<IntroAccountState>(&pda_intro.data.borrow()).unwrap();
-    msg!("borrowed account data");
-
-    // Account is_initialized validation
-    if !account_data.is_initialized() {
-        msg!("Account is not initialized");
-        return Err(IntroError::UninitializedAccount.into());
-    }
-
     msg!("Intro before update:");
     msg!("Name: {}", account_data.name);
     msg!("Message: {}", account_data.message);
 
     account_data.name = name;
     account_data.message = message;
+    // Updates always store the message inline
+    account_data.content_hash = None;
 
     msg!("Intro after update:");
     msg!("Name: {}", account_data.name);
     msg!("Message: {}", account_data.message);
 
     msg!("serializing account");
+    // Clear the old data first, a shorter message would otherwise leave stale bytes where trailing fields are read
+    let mut intro_bytes = pda_intro.data.borrow_mut();
+    intro_bytes.fill(0);
     // Take 'account_data' object, serialize it, set it to writer object
-    account_data.serialize(&mut &mut pda_intro.data.borrow_mut()[..])?;
+    account_data.serialize(&mut &mut intro_bytes[..])?;
     msg!("PDA updated");
 
     Ok(())
@@ -0,0 +1,81 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use borsh::BorshSerialize;
+use crate::state::UserProfile;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::validators::{check_signer, check_writable, check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+pub struct UpdateProfileAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_profile: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> UpdateProfileAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let ctx = Self {
+            wallet: next_account_info(account_info_iter)?,
+            pda_profile: next_account_info(account_info_iter)?,
+        };
+
+        // Check that `wallet` is signer
+        check_signer(ctx.wallet)?;
+
+        // Validate that program owns PDA
+        check_owner(ctx.pda_profile, program_id)?;
+        check_writable(ctx.pda_profile)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn update_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    display_name: String,
+    avatar_uri: String,
+    bio: String
+) -> ProgramResult {
+    msg!("Updating profile...");
+    msg!("Display name: {}", display_name);
+
+    let UpdateProfileAccounts { wallet, pda_profile } = UpdateProfileAccounts::parse(program_id, accounts)?;
+
+    // Ensure data length <1000 bytes
+    let account_len = 1000;
+    if UserProfile::get_account_size(display_name.clone(), avatar_uri.clone(), bio.clone()) > account_len {
+        msg!("Data length is larger than 1000 bytes");
+        return Err(IntroError::InvalidDataLength.into())
+    }
+
+    msg!("unpacking Profile PDA");
+    let mut account_data = decode_account::<UserProfile>(pda_profile)?;
+
+    // Account is_initialized validation
+    if !account_data.is_initialized() {
+        msg!("Profile PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    // Validate pda_profile is expected
+    check_seeds(pda_profile, &pda::profile_seeds(wallet.key), account_data.bump, program_id)?;
+
+    // Counters are only ever touched by the program, keep them as-is
+    account_data.display_name = display_name;
+    account_data.avatar_uri = avatar_uri;
+    account_data.bio = bio;
+
+    account_data.serialize(&mut &mut pda_profile.data.borrow_mut()[..])?;
+    msg!("Profile PDA updated");
+
+    Ok(())
+}
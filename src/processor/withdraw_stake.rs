@@ -0,0 +1,148 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::{next_account_info, AccountInfo},
+    sysvar::{clock::Clock, Sysvar},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    native_token::LAMPORTS_PER_SOL,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+use spl_associated_token_account::{instruction::create_associated_token_account, ID as ASSOCIATED_TOKEN_PROGRAM_ID};
+use borsh::BorshSerialize;
+use crate::error::IntroError;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::load_config::load_config;
+use crate::utils::load_stake::load_stake;
+use crate::utils::validators::{check_signer, check_writable, check_program_id, check_ata, check_stake_vault_accounts};
+use crate::pda;
+
+pub struct WithdrawStakeAccounts<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub pda_stake: &'a AccountInfo<'info>,
+    pub user_ata: &'a AccountInfo<'info>,
+    pub vault_auth: &'a AccountInfo<'info>,
+    pub vault_ata: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub vault_auth_bump: u8,
+}
+
+impl<'a, 'info> WithdrawStakeAccounts<'a, 'info> {
+    pub fn parse(program_id: &Pubkey, accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+
+        let wallet = next_account_info(account_info_iter)?;
+        let pda_stake = next_account_info(account_info_iter)?;
+        let user_ata = next_account_info(account_info_iter)?;
+        let vault_auth = next_account_info(account_info_iter)?;
+        let vault_ata = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let associated_token_program = next_account_info(account_info_iter)?;
+        let pda_config = next_account_info(account_info_iter)?;
+
+        check_signer(wallet)?;
+        check_writable(wallet)?;
+        check_writable(pda_stake)?;
+
+        check_program_id(system_program, &SYSTEM_PROGRAM_ID)?;
+        check_program_id(associated_token_program, &ASSOCIATED_TOKEN_PROGRAM_ID)?;
+
+        let config = load_config(program_id, pda_config)?;
+        let vault_auth_bump = check_stake_vault_accounts(program_id, &config, token_mint, vault_auth, vault_ata, token_program)?;
+        check_ata(user_ata, wallet.key, token_mint.key)?;
+        check_writable(user_ata)?;
+
+        Ok(Self {
+            wallet,
+            pda_stake,
+            user_ata,
+            vault_auth,
+            vault_ata,
+            token_mint,
+            system_program,
+            token_program,
+            associated_token_program,
+            vault_auth_bump,
+        })
+    }
+}
+
+// Return unbonded tokens from the stake vault to the wallet
+pub fn withdraw_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Withdrawing unstaked tokens...");
+
+    let WithdrawStakeAccounts {
+        wallet,
+        pda_stake,
+        user_ata,
+        vault_auth,
+        vault_ata,
+        token_mint,
+        system_program,
+        token_program,
+        associated_token_program,
+        vault_auth_bump,
+    } = WithdrawStakeAccounts::parse(program_id, accounts)?;
+
+    let mut stake_data = load_stake(program_id, pda_stake, wallet.key)?;
+
+    let amount = stake_data.unbonding_amount;
+    if amount == 0 {
+        msg!("Nothing to withdraw");
+        return Ok(());
+    }
+
+    let clock = Clock::get()?;
+    if clock.slot < stake_data.unbonding_end_slot {
+        msg!("Unbonding ends at slot {}", stake_data.unbonding_end_slot);
+        return Err(IntroError::StakeUnbonding.into());
+    }
+
+    stake_data.unbonding_amount = 0;
+    stake_data.serialize(&mut &mut pda_stake.data.borrow_mut()[..])?;
+
+    if !is_account_initialized(user_ata) {
+        msg!("ATA not created, creating...");
+        let instruction = create_associated_token_account(wallet.key, wallet.key, token_mint.key, token_program.key);
+        invoke(
+            &instruction,
+            &[
+                wallet.clone(),
+                user_ata.clone(),
+                token_mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                associated_token_program.clone(),
+            ]
+        )?;
+        msg!("Created user ATA");
+    }
+
+    invoke_signed(
+        // Instruction
+        &spl_token::instruction::transfer(
+            token_program.key,
+            vault_ata.key,
+            user_ata.key,
+            vault_auth.key,
+            &[],
+            amount,
+        )?,
+        // Account_infos
+        &[vault_ata.clone(), user_ata.clone(), vault_auth.clone()],
+        // Seeds
+        &[&[pda::STAKE_VAULT_SEED, &[vault_auth_bump]]],
+    )?;
+    msg!("Withdrew {} tokens, {} still staked", amount / LAMPORTS_PER_SOL, stake_data.amount / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
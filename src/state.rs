@@ -1,10 +1,17 @@
 // Struct PDA data fields
 
 use borsh::{BorshSerialize, BorshDeserialize};
+use bytemuck::{Pod, Zeroable};
 use solana_program::{
     pubkey::Pubkey,
-    program_pack::{IsInitialized, Sealed}
+    program_error::ProgramError,
+    program_pack::{IsInitialized, Sealed},
+    native_token::LAMPORTS_PER_SOL
 };
+use std::mem::size_of;
+use crate::error::IntroError;
+use crate::merkle::REPLY_TREE_DEPTH;
+use crate::validation::{FieldLimits, MAX_MESSAGE_BYTES};
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct IntroAccountState {
@@ -13,11 +20,47 @@ pub struct IntroAccountState {
     pub is_initialized: bool,
     pub introducer: Pubkey,
     pub name: String,
-    pub message: String  
+    pub message: String,
+    pub index: u64,
+    pub community: Option<Pubkey>,
+    pub bump: u8,
+    // Set for off-chain content, `message` then holds the content URI
+    pub content_hash: Option<[u8; 32]>,
+    // Tokens burned through BoostIntro, decayed up to `boost_slot`
+    pub boost_score: u64,
+    pub boost_slot: u64
+}
+
+// Per-wallet count of intros, the next intro is created at index `count`
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct IntroCounter {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub count: u64,
+    pub bump: u8
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct IntroReplyCounter {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub counter: u64,
+    pub bump: u8
+}
+
+// Intro layout from before intros were indexed, only read by MigrateIntro
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct LegacyIntroAccountState {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub introducer: Pubkey,
+    pub name: String,
+    pub message: String
+}
+
+// Reply counter layout from before bumps were stored, only read by MigrateIntro
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct LegacyIntroReplyCounter {
     pub discriminator: String,
     pub is_initialized: bool,
     pub counter: u64
@@ -30,7 +73,215 @@ pub struct IntroReply {
     pub intro: Pubkey,
     pub replier: Pubkey,
     pub reply: String,
-    pub count: u64
+    pub count: u64,
+    pub community: Option<Pubkey>,
+    pub bump: u8,
+    // Set for off-chain content, `reply` then holds the content URI
+    pub content_hash: Option<[u8; 32]>
+}
+
+// Lamports escrowed by a replier for the duration of the challenge window
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ReplyBond {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub intro: Pubkey,
+    pub reply: Pubkey,
+    pub replier: Pubkey,
+    pub amount: u64,
+    pub challenge_end_slot: u64,
+    pub bump: u8
+}
+
+// Identity PDA tracking a wallet across intros and replies
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UserProfile {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub wallet: Pubkey,
+    pub display_name: String,
+    pub avatar_uri: String,
+    pub bio: String,
+    pub replies_written: u64,
+    pub replies_received: u64,
+    pub tokens_earned: u64,
+    pub follower_count: u64,
+    pub following_count: u64,
+    pub follow_reward_claimed: bool,
+    pub bump: u8
+}
+
+// Edge in the follow graph, exists only while `follower` follows `followee`
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Follow {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub bump: u8
+}
+
+// Namespace that intros and their replies can optionally be scoped to
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Community {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub creator: Pubkey,
+    pub name: String,
+    pub description: String,
+    // Can flag replies to intros in the community alongside the intro owner
+    pub moderators: Vec<Pubkey>,
+    // Percentage applied to intro and reply rewards, 100 = 1x
+    pub reward_multiplier: u16,
+    pub bump: u8
+}
+
+// Append-only Merkle tree of compressed replies to one intro, only the frontier and root are kept
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ReplyTree {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub intro: Pubkey,
+    pub leaf_count: u64,
+    pub root: [u8; 32],
+    pub filled_subtrees: [[u8; 32]; REPLY_TREE_DEPTH],
+    pub bump: u8
+}
+
+// Rewards owed to a wallet, minted as they unlock through ClaimVested
+// Each reward vests on its own schedule, rewards landing in the same TRANCHE_SLOTS bucket share a tranche
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct VestingAccount {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub wallet: Pubkey,
+    pub cliff_slots: u64,
+    pub duration_slots: u64,
+    // Tranches with `total` 0 are free
+    pub tranches: [VestingTranche; VestingAccount::MAX_TRANCHES],
+    // Vested and unclaimed rewards from tranches or clawbacks
+    pub unlocked: u64,
+    pub claimed: u64,
+    pub clawed_back: u64,
+    pub bump: u8
+}
+
+// Rewards vesting linearly from `start_slot`, nothing before the cliff
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VestingTranche {
+    pub start_slot: u64,
+    pub total: u64,
+    // Vested part already moved to `unlocked`
+    pub released: u64,
+}
+
+// Reward tokens a wallet has locked in the stake vault, `amount` sets its stake tier
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct StakeAccount {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    // Unstaked tokens waiting out the unbonding period, they no longer count towards the tier
+    pub unbonding_amount: u64,
+    pub unbonding_end_slot: u64,
+    pub bump: u8
+}
+
+// Reward tokens an introducer escrows on their intro, held in the ATA owned by this PDA
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Bounty {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub intro: Pubkey,
+    pub introducer: Pubkey,
+    pub amount: u64,
+    // Slot from which the introducer can cancel and take the bounty back
+    pub expiry_slot: u64,
+    pub bump: u8
+}
+
+// Program-wide settings, created alongside the reward token mint
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub admin: Pubkey,
+    pub mint_bump: u8,
+    pub mint_auth_bump: u8,
+    // Bump of the stake vault authority PDA
+    pub stake_vault_bump: u8,
+    pub bump: u8,
+    // Tokens vested to the referrer of a new introducer
+    pub referral_reward: u64,
+    // Cap on the reward mint supply, 0 leaves minting uncapped
+    pub max_supply: u64,
+    // Every reward token minted through the program, burns do not reduce it
+    pub total_minted: u64,
+    // Who holds the mint authority, reward mints only work while it is the token_auth PDA
+    pub mint_authority_mode: MintAuthorityMode,
+    // Parameters set through governance, None keeps the built-in default
+    pub intro_reward: Option<u64>,
+    pub reply_reward: Option<u64>,
+    pub max_message_bytes: Option<u32>,
+    // Number of proposals created, the next proposal is created at index `proposal_count`
+    pub proposal_count: u64
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintAuthorityMode {
+    Program,
+    // Handed to a multisig or governance program, which mints outside this program
+    External { authority: Pubkey },
+    // Minting disabled permanently
+    Revoked,
+}
+
+// Program parameter change that token holders vote on
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    SetIntroReward { amount: u64 },
+    SetReplyReward { amount: u64 },
+    // Byte limit on intro messages and replies before stake tiers are applied
+    SetMaxMessageBytes { max_bytes: u32 },
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Proposal {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub end_slot: u64,
+    // Staked tokens behind each side
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub executed: bool,
+    pub bump: u8
+}
+
+// Exists once `voter` has voted on `proposal`
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct VoteRecord {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub bump: u8
+}
+
+// Records who referred `referee`, so each wallet can only be referred once
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Referral {
+    pub discriminator: String,
+    pub is_initialized: bool,
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub reward: u64,
+    pub bump: u8
 }
 
 // https://stackoverflow.com/questions/76213582/errore0277-the-trait-bound-movieaccountstate-borshdeborshdeserialize-i
@@ -38,32 +289,330 @@ impl IntroAccountState {
     pub const DISCRIMINATOR: &'static str = "intro";
 
     pub fn get_account_size(name: String, message: String) -> usize {
-        return (4 + IntroAccountState::DISCRIMINATOR.len())
+        (4 + IntroAccountState::DISCRIMINATOR.len())
             + 1
             + 32
             + (4 + name.len())
-            + (4 + message.len());
+            + (4 + message.len())
+            + 8
+            + (1 + 32)
+            + 1
+            + (1 + 32)
+            + 8
+            + 8
+    }
+
+    // Boost halves every half-life, linearly in between
+    pub const BOOST_HALF_LIFE_SLOTS: u64 = 216_000;
+
+    // Boost score as of `slot`, used to rank featured intros
+    pub fn decayed_boost(&self, slot: u64) -> u64 {
+        let elapsed = slot.saturating_sub(self.boost_slot);
+        let half_lives = elapsed / IntroAccountState::BOOST_HALF_LIFE_SLOTS;
+        if half_lives >= 64 {
+            return 0;
+        }
+        let halved = self.boost_score >> half_lives;
+        let remainder = elapsed % IntroAccountState::BOOST_HALF_LIFE_SLOTS;
+        let decay = (halved as u128) * (remainder as u128) / (2 * IntroAccountState::BOOST_HALF_LIFE_SLOTS as u128);
+        halved - decay as u64
+    }
+
+    pub fn boost(&mut self, amount: u64, slot: u64) -> Result<(), ProgramError> {
+        self.boost_score = self.decayed_boost(slot).checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+        self.boost_slot = slot;
+        Ok(())
     }
 }
 
+impl IntroCounter {
+    pub const DISCRIMINATOR: &'static str = "intro_counter";
+    pub const SIZE: usize = (4 + IntroCounter::DISCRIMINATOR.len()) + 1 + 8 + 1;
+}
+
 impl IntroReplyCounter {
     pub const DISCRIMINATOR: &'static str = "counter";
-    pub const SIZE: usize = (4 + IntroReplyCounter::DISCRIMINATOR.len()) + 1 + 8;
+    pub const SIZE: usize = (4 + IntroReplyCounter::DISCRIMINATOR.len()) + 1 + 8 + 1;
 }
 
 impl IntroReply {
     pub const DISCRIMINATOR: &'static str = "reply";
 
     pub fn get_account_size(reply: String) -> usize {
-        return (4 + IntroReply::DISCRIMINATOR.len()) 
+        (4 + IntroReply::DISCRIMINATOR.len()) 
             + 1 
             + 32 
             + 32 
             + (4 + reply.len()) 
-            + 8;
+            + 8
+            + (1 + 32)
+            + 1
+            + (1 + 32)
+    }
+}
+
+impl ReplyBond {
+    pub const DISCRIMINATOR: &'static str = "bond";
+    pub const SIZE: usize = (4 + ReplyBond::DISCRIMINATOR.len()) + 1 + 32 + 32 + 32 + 8 + 8 + 1;
+    // 0.01 SOL on top of rent
+    pub const AMOUNT: u64 = LAMPORTS_PER_SOL / 100;
+    // ~1 day at 400ms slots
+    pub const CHALLENGE_WINDOW_SLOTS: u64 = 216_000;
+}
+
+impl UserProfile {
+    pub const DISCRIMINATOR: &'static str = "profile";
+
+    pub fn get_account_size(display_name: String, avatar_uri: String, bio: String) -> usize {
+        (4 + UserProfile::DISCRIMINATOR.len())
+            + 1
+            + 32
+            + (4 + display_name.len())
+            + (4 + avatar_uri.len())
+            + (4 + bio.len())
+            + 8
+            + 8
+            + 8
+            + 8
+            + 8
+            + 1
+            + 1
     }
 }
 
+impl Follow {
+    pub const DISCRIMINATOR: &'static str = "follow";
+    pub const SIZE: usize = (4 + Follow::DISCRIMINATOR.len()) + 1 + 32 + 32 + 1;
+}
+
+impl Community {
+    pub const DISCRIMINATOR: &'static str = "community";
+    // Name is used as a PDA seed
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_MODERATORS: usize = 10;
+    // Anyone can create a community, so its multiplier can only lower rewards
+    pub const MAX_REWARD_MULTIPLIER: u16 = 100;
+
+    pub fn get_account_size(name: String, description: String, moderators: &[Pubkey]) -> usize {
+        (4 + Community::DISCRIMINATOR.len())
+            + 1
+            + 32
+            + (4 + name.len())
+            + (4 + description.len())
+            + (4 + 32 * moderators.len())
+            + 2
+            + 1
+    }
+
+    pub fn apply_reward_multiplier(&self, amount: u64) -> Result<u64, ProgramError> {
+        amount
+            .checked_mul(self.reward_multiplier as u64)
+            .map(|scaled| scaled / 100)
+            .ok_or_else(|| IntroError::ArithmeticOverflow.into())
+    }
+}
+
+impl ReplyTree {
+    pub const DISCRIMINATOR: &'static str = "reply_tree";
+    pub const SIZE: usize = (4 + ReplyTree::DISCRIMINATOR.len()) + 1 + 32 + 8 + 32 + 32 * REPLY_TREE_DEPTH + 1;
+}
+
+impl VestingAccount {
+    pub const DISCRIMINATOR: &'static str = "vesting";
+    pub const SIZE: usize = (4 + VestingAccount::DISCRIMINATOR.len()) + 1 + 32 + 8 + 8 + VestingAccount::MAX_TRANCHES * 8 * 3 + 8 * 3 + 1;
+    // ~7 day cliff, then linear until ~30 days at 400ms slots
+    pub const CLIFF_SLOTS: u64 = 1_512_000;
+    pub const DURATION_SLOTS: u64 = 6_480_000;
+    // ~2 days, a reward starts vesting at the end of its bucket so it never unlocks before its own cliff
+    pub const TRANCHE_SLOTS: u64 = 432_000;
+    // Enough for every bucket a tranche can still be vesting in
+    pub const MAX_TRANCHES: usize = (VestingAccount::DURATION_SLOTS / VestingAccount::TRANCHE_SLOTS) as usize + 1;
+
+    fn vested(&self, tranche: &VestingTranche, slot: u64) -> Result<u64, ProgramError> {
+        let elapsed = slot.saturating_sub(tranche.start_slot);
+        if elapsed < self.cliff_slots {
+            return Ok(0);
+        }
+        if elapsed >= self.duration_slots {
+            return Ok(tranche.total);
+        }
+        let vested = (tranche.total as u128) * (elapsed as u128) / (self.duration_slots as u128);
+        u64::try_from(vested).map_err(|_| IntroError::ArithmeticOverflow.into())
+    }
+
+    // Move what each tranche has vested by `slot` into `unlocked`, freeing fully vested tranches
+    fn settle(&mut self, slot: u64) -> Result<(), ProgramError> {
+        for index in 0..self.tranches.len() {
+            let tranche = self.tranches[index];
+            if tranche.total == 0 {
+                continue;
+            }
+            let vested = self.vested(&tranche, slot)?;
+            let newly_unlocked = vested.checked_sub(tranche.released).ok_or(IntroError::ArithmeticOverflow)?;
+            self.unlocked = self.unlocked.checked_add(newly_unlocked).ok_or(IntroError::ArithmeticOverflow)?;
+            self.tranches[index] = if vested == tranche.total {
+                VestingTranche::default()
+            } else {
+                VestingTranche { released: vested, ..tranche }
+            };
+        }
+        Ok(())
+    }
+
+    // Rewards not yet vested
+    pub fn locked(&self) -> Result<u64, ProgramError> {
+        self.tranches.iter().try_fold(0u64, |locked, tranche| {
+            tranche.total.checked_sub(tranche.released)
+                .and_then(|remaining| locked.checked_add(remaining))
+                .ok_or_else(|| IntroError::ArithmeticOverflow.into())
+        })
+    }
+
+    pub fn claimable(&self, slot: u64) -> Result<u64, ProgramError> {
+        self.tranches.iter().filter(|tranche| tranche.total != 0).try_fold(self.unlocked, |claimable, tranche| {
+            self.vested(tranche, slot)?
+                .checked_sub(tranche.released)
+                .and_then(|vested| vested.checked_add(claimable))
+                .ok_or_else(|| IntroError::ArithmeticOverflow.into())
+        })
+    }
+
+    pub fn accrue(&mut self, amount: u64, slot: u64) -> Result<(), ProgramError> {
+        self.settle(slot)?;
+        let start_slot = slot.div_ceil(VestingAccount::TRANCHE_SLOTS)
+            .checked_mul(VestingAccount::TRANCHE_SLOTS)
+            .ok_or(IntroError::ArithmeticOverflow)?;
+
+        // Earlier tranches keep their schedules, the reward joins its bucket's tranche or takes a free one
+        let index = self.tranches.iter().position(|tranche| tranche.total != 0 && tranche.start_slot == start_slot)
+            .or_else(|| self.tranches.iter().position(|tranche| tranche.total == 0))
+            .ok_or(IntroError::VestingTranchesFull)?;
+        let tranche = &mut self.tranches[index];
+        if tranche.total == 0 {
+            *tranche = VestingTranche { start_slot, total: 0, released: 0 };
+        }
+        tranche.total = tranche.total.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    // Move everything unlocked at `slot` out of the account and return it
+    pub fn claim(&mut self, slot: u64) -> Result<u64, ProgramError> {
+        self.settle(slot)?;
+        let amount = self.unlocked;
+        self.unlocked = 0;
+        self.claimed = self.claimed.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(amount)
+    }
+
+    // Drop every still-locked tranche, vested rewards stay claimable
+    pub fn claw_back(&mut self, slot: u64) -> Result<u64, ProgramError> {
+        self.settle(slot)?;
+        let clawed = self.locked()?;
+        self.tranches = [VestingTranche::default(); VestingAccount::MAX_TRANCHES];
+        self.clawed_back = self.clawed_back.checked_add(clawed).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(clawed)
+    }
+}
+
+impl StakeAccount {
+    pub const DISCRIMINATOR: &'static str = "stake";
+    pub const SIZE: usize = (4 + StakeAccount::DISCRIMINATOR.len()) + 1 + 32 + 8 + 8 + 8 + 1;
+    // ~2 days at 400ms slots
+    pub const UNBONDING_SLOTS: u64 = 432_000;
+    // Minimum stake for tiers 1, 2 and 3
+    pub const TIER_THRESHOLDS: [u64; 3] = [100 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL, 10_000 * LAMPORTS_PER_SOL];
+    pub const MAX_TIER: u8 = StakeAccount::TIER_THRESHOLDS.len() as u8;
+
+    pub fn tier(&self) -> u8 {
+        StakeAccount::TIER_THRESHOLDS.iter().filter(|threshold| self.amount >= **threshold).count() as u8
+    }
+}
+
+impl Bounty {
+    pub const DISCRIMINATOR: &'static str = "bounty";
+    pub const SIZE: usize = (4 + Bounty::DISCRIMINATOR.len()) + 1 + 32 + 32 + 8 + 8 + 1;
+}
+
+impl Config {
+    pub const DISCRIMINATOR: &'static str = "config";
+    pub const SIZE: usize = (4 + Config::DISCRIMINATOR.len()) + 1 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 8 + (1 + 32) + (1 + 8) + (1 + 8) + (1 + 4) + 8;
+    pub const DEFAULT_REFERRAL_REWARD: u64 = 5 * LAMPORTS_PER_SOL;
+    pub const DEFAULT_MAX_SUPPLY: u64 = 1_000_000_000 * LAMPORTS_PER_SOL;
+    pub const DEFAULT_INTRO_REWARD: u64 = 10 * LAMPORTS_PER_SOL;
+    pub const DEFAULT_REPLY_REWARD: u64 = 5 * LAMPORTS_PER_SOL;
+
+    pub fn intro_reward_amount(&self) -> u64 {
+        self.intro_reward.unwrap_or(Config::DEFAULT_INTRO_REWARD)
+    }
+
+    pub fn reply_reward_amount(&self) -> u64 {
+        self.reply_reward.unwrap_or(Config::DEFAULT_REPLY_REWARD)
+    }
+
+    // Apply the governed byte limit, if any, to message or reply limits
+    pub fn message_limits(&self, limits: FieldLimits) -> FieldLimits {
+        match self.max_message_bytes {
+            Some(max_bytes) => FieldLimits { max_bytes: max_bytes as usize, ..limits },
+            None => limits,
+        }
+    }
+
+    // Count `amount` towards `total_minted`, failing if minting it on top of the mint's `supply` would pass `max_supply`
+    pub fn record_mint(&mut self, supply: u64, amount: u64) -> Result<(), ProgramError> {
+        let new_supply = supply.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+        if self.max_supply != 0 && new_supply > self.max_supply {
+            return Err(IntroError::MaxSupplyExceeded.into());
+        }
+        self.total_minted = self.total_minted.checked_add(amount).ok_or(IntroError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+impl Proposal {
+    pub const DISCRIMINATOR: &'static str = "proposal";
+    pub const SIZE: usize = (4 + Proposal::DISCRIMINATOR.len()) + 1 + 8 + 32 + (1 + 8) + 8 + 8 + 8 + 1 + 1;
+    // Voting lasts no longer than unbonding, so staked tokens cannot be withdrawn and voted again from another wallet
+    pub const VOTING_SLOTS: u64 = StakeAccount::UNBONDING_SLOTS;
+    // Stake needed to create a proposal
+    pub const PROPOSAL_THRESHOLD: u64 = StakeAccount::TIER_THRESHOLDS[0];
+    // Approving stake needed for a proposal to pass
+    pub const QUORUM: u64 = 10_000 * LAMPORTS_PER_SOL;
+    // Upper bounds on SetIntroReward and SetReplyReward, ten times the defaults
+    pub const MAX_INTRO_REWARD: u64 = 10 * Config::DEFAULT_INTRO_REWARD;
+    pub const MAX_REPLY_REWARD: u64 = 10 * Config::DEFAULT_REPLY_REWARD;
+
+    pub fn passed(&self) -> bool {
+        self.yes_votes > self.no_votes && self.yes_votes >= Proposal::QUORUM
+    }
+}
+
+impl ProposalAction {
+    // Checked when proposing and again when executing, so a proposal can never set a value outside these bounds
+    pub fn check_bounds(&self) -> Result<(), ProgramError> {
+        let in_bounds = match *self {
+            ProposalAction::SetIntroReward { amount } => amount <= Proposal::MAX_INTRO_REWARD,
+            ProposalAction::SetReplyReward { amount } => amount <= Proposal::MAX_REPLY_REWARD,
+            ProposalAction::SetMaxMessageBytes { max_bytes } => max_bytes != 0 && max_bytes as usize <= MAX_MESSAGE_BYTES,
+        };
+        if !in_bounds {
+            return Err(IntroError::ProposalOutOfBounds.into());
+        }
+        Ok(())
+    }
+}
+
+impl VoteRecord {
+    pub const DISCRIMINATOR: &'static str = "vote";
+    pub const SIZE: usize = (4 + VoteRecord::DISCRIMINATOR.len()) + 1 + 32 + 32 + 1 + 8 + 1;
+}
+
+impl Referral {
+    pub const DISCRIMINATOR: &'static str = "referral";
+    pub const SIZE: usize = (4 + Referral::DISCRIMINATOR.len()) + 1 + 32 + 32 + 8 + 1;
+    // Stake tier a referred wallet needs before its referrer is rewarded
+    pub const MIN_REFEREE_TIER: u8 = 1;
+}
+
 impl Sealed for IntroAccountState {}
 
 impl IsInitialized for IntroAccountState {
@@ -72,14 +621,420 @@ impl IsInitialized for IntroAccountState {
     }
 }
 
+impl IsInitialized for IntroCounter {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
 impl IsInitialized for IntroReplyCounter {
     fn is_initialized(&self) -> bool {
         self.is_initialized
     }
 }
 
+impl IsInitialized for Config {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for LegacyIntroAccountState {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for LegacyIntroReplyCounter {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
 impl IsInitialized for IntroReply {
     fn is_initialized(&self) -> bool {
         self.is_initialized
     }
-}
\ No newline at end of file
+}
+
+impl IsInitialized for ReplyBond {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for UserProfile {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for Follow {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for ReplyTree {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for VestingAccount {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for StakeAccount {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for Bounty {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for Proposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for VoteRecord {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for Referral {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl IsInitialized for Community {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+// Zero-copy views over the Borsh encoding of fixed-size records
+// Every field is a byte array so the structs have alignment 1 and can be cast straight from account data
+
+// Byte-for-byte layout of a Borsh-encoded IntroReplyCounter
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct IntroReplyCounterZc {
+    discriminator_len: [u8; 4],
+    discriminator: [u8; 7],
+    is_initialized: u8,
+    counter: [u8; 8],
+    bump: u8
+}
+
+// Byte-for-byte layout of the fixed-size fields at the start of a Borsh-encoded IntroReply
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct IntroReplyHeaderZc {
+    discriminator_len: [u8; 4],
+    discriminator: [u8; 5],
+    is_initialized: u8,
+    intro: Pubkey,
+    replier: Pubkey
+}
+
+const _: () = assert!(size_of::<IntroReplyCounterZc>() == IntroReplyCounter::SIZE);
+const _: () = assert!(size_of::<IntroReplyHeaderZc>() == (4 + IntroReply::DISCRIMINATOR.len()) + 1 + 32 + 32);
+
+impl IntroReplyCounterZc {
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        bytemuck::try_from_bytes(data.get(..size_of::<Self>()).ok_or(ProgramError::AccountDataTooSmall)?)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        bytemuck::try_from_bytes_mut(data.get_mut(..size_of::<Self>()).ok_or(ProgramError::AccountDataTooSmall)?)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn initialize(&mut self, bump: u8) {
+        self.discriminator_len = (IntroReplyCounter::DISCRIMINATOR.len() as u32).to_le_bytes();
+        self.discriminator.copy_from_slice(IntroReplyCounter::DISCRIMINATOR.as_bytes());
+        self.is_initialized = 1;
+        self.counter = 0u64.to_le_bytes();
+        self.bump = bump;
+    }
+
+    pub fn has_valid_discriminator(&self) -> bool {
+        u32::from_le_bytes(self.discriminator_len) as usize == IntroReplyCounter::DISCRIMINATOR.len()
+            && self.discriminator == IntroReplyCounter::DISCRIMINATOR.as_bytes()
+    }
+
+    pub fn counter(&self) -> u64 {
+        u64::from_le_bytes(self.counter)
+    }
+
+    pub fn set_counter(&mut self, counter: u64) {
+        self.counter = counter.to_le_bytes();
+    }
+
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl IntroReplyHeaderZc {
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        bytemuck::try_from_bytes(data.get(..size_of::<Self>()).ok_or(ProgramError::AccountDataTooSmall)?)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn has_valid_discriminator(&self) -> bool {
+        u32::from_le_bytes(self.discriminator_len) as usize == IntroReply::DISCRIMINATOR.len()
+            && self.discriminator == IntroReply::DISCRIMINATOR.as_bytes()
+    }
+
+    pub fn intro(&self) -> &Pubkey {
+        &self.intro
+    }
+
+    pub fn replier(&self) -> &Pubkey {
+        &self.replier
+    }
+}
+
+impl IsInitialized for IntroReplyCounterZc {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized != 0
+    }
+}
+
+impl IsInitialized for IntroReplyHeaderZc {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized != 0
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reply_counter_zc_reads_borsh_counter() {
+        let counter = IntroReplyCounter {
+            discriminator: IntroReplyCounter::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            counter: 42,
+            bump: 254
+        };
+        let data = counter.try_to_vec().unwrap();
+        assert_eq!(data.len(), IntroReplyCounter::SIZE);
+
+        let view = IntroReplyCounterZc::load(&data).unwrap();
+        assert!(view.has_valid_discriminator());
+        assert!(view.is_initialized());
+        assert_eq!(view.counter(), 42);
+        assert_eq!(view.bump(), 254);
+    }
+
+    #[test]
+    fn reply_counter_zc_writes_borsh_counter() {
+        let mut data = vec![0; IntroReplyCounter::SIZE];
+        let view = IntroReplyCounterZc::load_mut(&mut data).unwrap();
+        view.initialize(7);
+        view.set_counter(u64::MAX);
+
+        let counter = IntroReplyCounter::try_from_slice(&data).unwrap();
+        assert_eq!(counter.discriminator, IntroReplyCounter::DISCRIMINATOR);
+        assert!(counter.is_initialized);
+        assert_eq!(counter.counter, u64::MAX);
+        assert_eq!(counter.bump, 7);
+    }
+
+    #[test]
+    fn reward_math_near_max() {
+        let community = Community {
+            discriminator: Community::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            creator: Pubkey::new_unique(),
+            name: "rust".to_string(),
+            description: String::new(),
+            moderators: Vec::new(),
+            reward_multiplier: Community::MAX_REWARD_MULTIPLIER,
+            bump: 0
+        };
+        assert_eq!(community.apply_reward_multiplier(u64::MAX / 100), Ok(u64::MAX / 100));
+        assert_eq!(community.apply_reward_multiplier(u64::MAX), Err(IntroError::ArithmeticOverflow.into()));
+
+        let mut intro = IntroAccountState {
+            discriminator: IntroAccountState::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            introducer: Pubkey::new_unique(),
+            name: String::new(),
+            message: String::new(),
+            index: 0,
+            community: None,
+            bump: 0,
+            content_hash: None,
+            boost_score: u64::MAX,
+            boost_slot: 0
+        };
+        assert_eq!(intro.decayed_boost(0), u64::MAX);
+        assert_eq!(intro.decayed_boost(u64::MAX), 0);
+        assert_eq!(intro.boost(1, 0), Err(IntroError::ArithmeticOverflow.into()));
+        // A half-life later there is room again
+        assert_eq!(intro.boost(1, IntroAccountState::BOOST_HALF_LIFE_SLOTS), Ok(()));
+        assert_eq!(intro.boost_score, u64::MAX / 2 + 1);
+    }
+
+    #[test]
+    fn total_minted_near_max() {
+        let mut config = Config {
+            discriminator: Config::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            admin: Pubkey::new_unique(),
+            mint_bump: 0,
+            mint_auth_bump: 0,
+            stake_vault_bump: 0,
+            bump: 0,
+            referral_reward: 0,
+            max_supply: 0,
+            total_minted: u64::MAX - 1,
+            mint_authority_mode: MintAuthorityMode::Program,
+            intro_reward: None,
+            reply_reward: None,
+            max_message_bytes: None,
+            proposal_count: 0
+        };
+        assert_eq!(config.record_mint(0, 1), Ok(()));
+        assert_eq!(config.record_mint(0, 1), Err(IntroError::ArithmeticOverflow.into()));
+        assert_eq!(config.record_mint(u64::MAX, 1), Err(IntroError::ArithmeticOverflow.into()));
+
+        // The cap applies to the mint supply, which includes tokens minted outside the counter
+        config.total_minted = 0;
+        config.max_supply = u64::MAX - 1;
+        assert_eq!(config.record_mint(0, u64::MAX), Err(IntroError::MaxSupplyExceeded.into()));
+        assert_eq!(config.record_mint(u64::MAX - 1, 1), Err(IntroError::MaxSupplyExceeded.into()));
+        assert_eq!(config.record_mint(u64::MAX - 2, 1), Ok(()));
+        assert_eq!(config.total_minted, 1);
+    }
+
+    fn empty_vesting() -> VestingAccount {
+        VestingAccount {
+            discriminator: VestingAccount::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            wallet: Pubkey::new_unique(),
+            cliff_slots: VestingAccount::CLIFF_SLOTS,
+            duration_slots: VestingAccount::DURATION_SLOTS,
+            tranches: [VestingTranche::default(); VestingAccount::MAX_TRANCHES],
+            unlocked: 0,
+            claimed: 0,
+            clawed_back: 0,
+            bump: 0
+        }
+    }
+
+    #[test]
+    fn vesting_near_max() {
+        let mut vesting = empty_vesting();
+        vesting.accrue(u64::MAX, 0).unwrap();
+        // The u128 intermediate keeps the linear schedule exact at the top of the range
+        assert_eq!(vesting.claimable(VestingAccount::DURATION_SLOTS / 2), Ok(u64::MAX / 2));
+        assert_eq!(vesting.accrue(1, 0), Err(IntroError::ArithmeticOverflow.into()));
+    }
+
+    #[test]
+    fn vesting_size_matches_serialized() {
+        assert_eq!(empty_vesting().try_to_vec().unwrap().len(), VestingAccount::SIZE);
+    }
+
+    #[test]
+    fn earlier_reward_keeps_its_cliff() {
+        let mut vesting = empty_vesting();
+        vesting.accrue(1_000, 0).unwrap();
+        vesting.accrue(1_000, VestingAccount::CLIFF_SLOTS - 1).unwrap();
+
+        // The first reward unlocks at its original cliff, the later one is still before its own
+        let cliff = VestingAccount::CLIFF_SLOTS;
+        let duration = VestingAccount::DURATION_SLOTS;
+        let second_start = (cliff - 1).div_ceil(VestingAccount::TRANCHE_SLOTS) * VestingAccount::TRANCHE_SLOTS;
+        assert_eq!(vesting.claimable(cliff - 1), Ok(0));
+        assert_eq!(vesting.claimable(cliff), Ok(1_000 * cliff / duration));
+        assert_eq!(vesting.claimable(duration), Ok(1_000 + 1_000 * (duration - second_start) / duration));
+        assert_eq!(vesting.claimable(second_start + duration), Ok(2_000));
+    }
+
+    #[test]
+    fn later_reward_never_unlocks_before_its_cliff() {
+        let mut vesting = empty_vesting();
+        let slot = VestingAccount::TRANCHE_SLOTS + 1;
+        vesting.accrue(1_000, slot).unwrap();
+        assert_eq!(vesting.claimable(slot + VestingAccount::CLIFF_SLOTS - 1), Ok(0));
+        assert_eq!(vesting.claimable(slot + VestingAccount::DURATION_SLOTS + VestingAccount::TRANCHE_SLOTS), Ok(1_000));
+    }
+
+    #[test]
+    fn frequent_rewards_keep_vesting() {
+        // A reward every ~hour for 60 days never runs out of tranches and unlocks as it goes
+        let mut vesting = empty_vesting();
+        let step = 9_000;
+        let mut claimed = 0;
+        for index in 0..(2 * VestingAccount::DURATION_SLOTS / step) {
+            let slot = index * step;
+            vesting.accrue(1_000, slot).unwrap();
+            claimed += vesting.claim(slot).unwrap();
+            if slot >= VestingAccount::DURATION_SLOTS {
+                assert!(claimed > 0);
+            }
+        }
+        let accrued = 1_000 * (2 * VestingAccount::DURATION_SLOTS / step);
+        assert_eq!(claimed + vesting.locked().unwrap() + vesting.unlocked, accrued);
+        assert_eq!(vesting.claimed, claimed);
+    }
+
+    #[test]
+    fn accrue_after_claim_keeps_totals() {
+        let mut vesting = empty_vesting();
+        vesting.accrue(1_000, 0).unwrap();
+        let slot = VestingAccount::DURATION_SLOTS / 2;
+        assert_eq!(vesting.claim(slot), Ok(500));
+        vesting.accrue(1_000, slot).unwrap();
+
+        // Nothing claimed is counted twice and nothing locked is lost
+        assert_eq!(vesting.unlocked, 0);
+        assert_eq!(vesting.locked(), Ok(1_500));
+        assert_eq!(vesting.claim(slot + VestingAccount::DURATION_SLOTS + VestingAccount::TRANCHE_SLOTS), Ok(1_500));
+        assert_eq!(vesting.claimed, 2_000);
+    }
+
+    #[test]
+    fn claw_back_keeps_vested_rewards() {
+        let mut vesting = empty_vesting();
+        vesting.accrue(1_000, 0).unwrap();
+        vesting.accrue(1_000, VestingAccount::DURATION_SLOTS / 2).unwrap();
+        assert_eq!(vesting.claw_back(VestingAccount::DURATION_SLOTS / 2), Ok(1_500));
+        assert_eq!(vesting.claimable(u64::MAX), Ok(500));
+        assert_eq!(vesting.clawed_back, 1_500);
+    }
+
+    #[test]
+    fn proposal_action_bounds() {
+        assert_eq!(ProposalAction::SetIntroReward { amount: Proposal::MAX_INTRO_REWARD }.check_bounds(), Ok(()));
+        assert_eq!(ProposalAction::SetIntroReward { amount: Proposal::MAX_INTRO_REWARD + 1 }.check_bounds(), Err(IntroError::ProposalOutOfBounds.into()));
+        assert_eq!(ProposalAction::SetReplyReward { amount: Proposal::MAX_REPLY_REWARD }.check_bounds(), Ok(()));
+        assert_eq!(ProposalAction::SetReplyReward { amount: u64::MAX }.check_bounds(), Err(IntroError::ProposalOutOfBounds.into()));
+        assert_eq!(ProposalAction::SetMaxMessageBytes { max_bytes: 0 }.check_bounds(), Err(IntroError::ProposalOutOfBounds.into()));
+        assert_eq!(ProposalAction::SetMaxMessageBytes { max_bytes: MAX_MESSAGE_BYTES as u32 }.check_bounds(), Ok(()));
+        assert_eq!(ProposalAction::SetMaxMessageBytes { max_bytes: MAX_MESSAGE_BYTES as u32 + 1 }.check_bounds(), Err(IntroError::ProposalOutOfBounds.into()));
+    }
+
+    #[test]
+    fn reply_counter_zc_rejects_short_data() {
+        let data = vec![0; IntroReplyCounter::SIZE - 1];
+        assert_eq!(IntroReplyCounterZc::load(&data).err(), Some(ProgramError::AccountDataTooSmall));
+    }
+}
@@ -0,0 +1,72 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    sysvar::{rent::Rent, clock::Clock, Sysvar},
+    native_token::LAMPORTS_PER_SOL,
+};
+use borsh::BorshSerialize;
+use crate::state::{VestingAccount, VestingTranche};
+use crate::pda;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::load_vesting::load_vesting;
+use crate::utils::validators::{check_writable, find_seeds};
+
+// Add `amount` to the vesting account of `wallet`, creating it on the wallet's first reward
+pub fn accrue_vesting<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    wallet: &Pubkey,
+    pda_vesting: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &Rent,
+    amount: u64
+) -> ProgramResult {
+    check_writable(pda_vesting)?;
+    let pda_seed = pda::vesting_seeds(wallet);
+
+    let mut vesting_data = if !is_account_initialized(pda_vesting) {
+        // Validate pda_vesting is expected
+        let bump_seed = find_seeds(pda_vesting, &pda_seed, program_id)?;
+
+        let account_len = VestingAccount::SIZE;
+
+        msg!("Creating Vesting PDA");
+        create_pda_account(
+            payer,
+            pda_vesting,
+            system_program,
+            program_id,
+            rent.minimum_balance(account_len),
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Vesting PDA created: {}", pda_vesting.key);
+
+        VestingAccount {
+            discriminator: VestingAccount::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            wallet: *wallet,
+            cliff_slots: VestingAccount::CLIFF_SLOTS,
+            duration_slots: VestingAccount::DURATION_SLOTS,
+            tranches: [VestingTranche::default(); VestingAccount::MAX_TRANCHES],
+            unlocked: 0,
+            claimed: 0,
+            clawed_back: 0,
+            bump: bump_seed
+        }
+    } else {
+        load_vesting(program_id, pda_vesting, wallet)?
+    };
+
+    let clock = Clock::get()?;
+    vesting_data.accrue(amount, clock.slot)?;
+
+    vesting_data.serialize(&mut &mut pda_vesting.data.borrow_mut()[..])?;
+    msg!("Accrued {} tokens to vesting, {} tokens locked", amount / LAMPORTS_PER_SOL, vesting_data.locked()? / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+};
+use crate::error::IntroError;
+
+// Drain all lamports from a program-owned account into `destination` and wipe its data
+// Runtime garbage-collects the account at the end of the transaction once it holds 0 lamports
+pub fn close_account(
+    account: &AccountInfo,
+    destination: &AccountInfo
+) -> Result<(), ProgramError> {
+    let destination_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(account.lamports())
+        .ok_or(IntroError::ArithmeticOverflow)?;
+    **account.lamports.borrow_mut() = 0;
+    account.data.borrow_mut().fill(0);
+    Ok(())
+}
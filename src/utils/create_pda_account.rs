@@ -0,0 +1,39 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    account_info::AccountInfo,
+    system_instruction,
+    program::invoke_signed,
+    program_error::ProgramError,
+};
+use std::convert::TryFrom;
+
+// Create `pda` through the system program, signing with its seeds and bump
+#[allow(clippy::too_many_arguments)]
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    pda: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    owner: &Pubkey,
+    lamports: u64,
+    space: usize,
+    seeds: &[&[u8]],
+    bump: u8
+) -> ProgramResult {
+    let space = u64::try_from(space).map_err(|_| ProgramError::InvalidArgument)?;
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            pda.key,
+            lamports,
+            space,
+            owner
+        ),
+        &[payer.clone(), pda.clone(), system_program.clone()],
+        &[&signer_seeds],
+    )
+}
@@ -0,0 +1,65 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    sysvar::rent::Rent,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use borsh::BorshSerialize;
+use crate::state::{IntroReply, IntroReplyHeaderZc, StakeAccount};
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::find_seeds;
+use crate::validation::{FieldLimits, REPLY_LIMITS, MAX_MESSAGE_BYTES};
+
+// Create the Reply PDA numbered `counter` under its intro and write `reply_data` into it, filling in the bump
+pub fn create_reply_account<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    pda_reply: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &Rent,
+    counter: u64,
+    mut reply_data: IntroReply
+) -> ProgramResult {
+    // Callers validate the reply against the governed limit and the replier's stake tier, this only bounds the largest both allow
+    let max_reply_limits = FieldLimits { max_bytes: MAX_MESSAGE_BYTES, ..REPLY_LIMITS };
+    let max_account_len = 1000 + max_reply_limits.for_stake_tier(StakeAccount::MAX_TIER).max_bytes - REPLY_LIMITS.max_bytes;
+    let account_len = IntroReply::get_account_size(reply_data.reply.clone());
+    if account_len > max_account_len {
+        msg!("Data length is larger than {} bytes", max_account_len);
+        return Err(IntroError::InvalidDataLength.into());
+    };
+
+    // Validate pda_reply is expected
+    let counter_value = counter.to_be_bytes();
+    let pda_seed = pda::reply_seeds(&reply_data.intro, &counter_value);
+    let bump_seed = find_seeds(pda_reply, &pda_seed, program_id)?;
+
+    msg!("Creating Reply PDA");
+    create_pda_account(
+        payer,
+        pda_reply,
+        system_program,
+        program_id,
+        rent.minimum_balance(account_len),
+        account_len,
+        &pda_seed,
+        bump_seed
+    )?;
+    msg!("Reply PDA created: {}", pda_reply.key);
+
+    // Only the fixed-size header is needed to check a fresh account, skip decoding the zeroed reply string
+    if IntroReplyHeaderZc::load(&pda_reply.data.borrow())?.is_initialized() {
+        msg!("Account already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    reply_data.bump = bump_seed;
+    reply_data.serialize(&mut &mut pda_reply.data.borrow_mut()[..])?;
+
+    Ok(())
+}
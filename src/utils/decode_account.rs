@@ -0,0 +1,15 @@
+use solana_program::{
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    borsh0_10::try_from_slice_unchecked,
+};
+use borsh::BorshDeserialize;
+
+// Borsh-decode account data, trailing bytes are allowed since accounts are allocated with headroom
+pub fn decode_account<T: BorshDeserialize>(account: &AccountInfo) -> Result<T, ProgramError> {
+    try_from_slice_unchecked::<T>(&account.data.borrow()).map_err(|_| {
+        msg!("Account {} data could not be decoded", account.key);
+        ProgramError::InvalidAccountData
+    })
+}
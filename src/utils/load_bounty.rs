@@ -0,0 +1,39 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::Bounty;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+use crate::utils::validators::{check_owner, check_seeds};
+
+// Load the bounty on `intro` and check `introducer` posted it
+pub fn load_bounty(
+    program_id: &Pubkey,
+    pda_bounty: &AccountInfo,
+    intro: &Pubkey,
+    introducer: &Pubkey
+) -> Result<Bounty, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(pda_bounty, program_id)?;
+
+    let bounty_data = decode_account::<Bounty>(pda_bounty)?;
+
+    if !bounty_data.is_initialized() || bounty_data.discriminator != Bounty::DISCRIMINATOR {
+        msg!("Bounty PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_seeds(pda_bounty, &pda::bounty_seeds(intro), bounty_data.bump, program_id)?;
+
+    if bounty_data.introducer != *introducer {
+        msg!("Signer did not post this bounty");
+        return Err(IntroError::Unauthorized.into());
+    }
+
+    Ok(bounty_data)
+}
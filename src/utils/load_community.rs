@@ -0,0 +1,38 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::Community;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::validators::{check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+// Load the Community account an intro or reply is scoped to
+pub fn load_community(
+    program_id: &Pubkey,
+    community: &AccountInfo,
+    expected: &Pubkey
+) -> Result<Community, ProgramError> {
+    if community.key != expected {
+        msg!("Community passed in does not match {}", expected);
+        return Err(IntroError::CommunityMismatch.into());
+    }
+
+    // Validate that program owns PDA
+    check_owner(community, program_id)?;
+
+    let community_data = decode_account::<Community>(community)?;
+
+    if !community_data.is_initialized() || community_data.discriminator != Community::DISCRIMINATOR {
+        msg!("Community PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_seeds(community, &pda::community_seeds(&community_data.name), community_data.bump, program_id)?;
+
+    Ok(community_data)
+}
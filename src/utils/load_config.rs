@@ -0,0 +1,32 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::Config;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::validators::{check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+// Load the program Config account, which carries the mint and mint authority bumps
+pub fn load_config(
+    program_id: &Pubkey,
+    config: &AccountInfo
+) -> Result<Config, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(config, program_id)?;
+
+    let config_data = decode_account::<Config>(config)?;
+
+    if !config_data.is_initialized() || config_data.discriminator != Config::DISCRIMINATOR {
+        msg!("Config PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_seeds(config, &pda::config_seeds(), config_data.bump, program_id)?;
+
+    Ok(config_data)
+}
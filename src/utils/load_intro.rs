@@ -0,0 +1,42 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::IntroAccountState;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::validators::{check_owner, check_seeds};
+use crate::utils::decode_account::decode_account;
+
+// Load an intro, rejecting other program accounts such as replies or counters passed in its place
+pub fn load_intro(
+    program_id: &Pubkey,
+    intro: &AccountInfo
+) -> Result<IntroAccountState, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(intro, program_id)?;
+
+    let intro_data = decode_account::<IntroAccountState>(intro)
+        .map_err(|_| IntroError::NotAnIntro)?;
+
+    if !intro_data.is_initialized() {
+        msg!("Intro PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    if intro_data.discriminator != IntroAccountState::DISCRIMINATOR {
+        msg!("Account {} is not an intro", intro.key);
+        return Err(IntroError::NotAnIntro.into());
+    }
+
+    // Re-derive the address from the introducer and index stored in the intro
+    let index_bytes = intro_data.index.to_be_bytes();
+    let pda_seed = pda::intro_seeds(&intro_data.community, &intro_data.introducer, &index_bytes);
+    check_seeds(intro, &pda_seed, intro_data.bump, program_id)
+        .map_err(|_| IntroError::IntroAddressMismatch)?;
+
+    Ok(intro_data)
+}
@@ -0,0 +1,33 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::Proposal;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+use crate::utils::validators::{check_owner, check_seeds};
+
+// Load a governance proposal, re-deriving its address from the stored index
+pub fn load_proposal(
+    program_id: &Pubkey,
+    pda_proposal: &AccountInfo
+) -> Result<Proposal, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(pda_proposal, program_id)?;
+
+    let proposal_data = decode_account::<Proposal>(pda_proposal)?;
+
+    if !proposal_data.is_initialized() || proposal_data.discriminator != Proposal::DISCRIMINATOR {
+        msg!("Proposal PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    let index_bytes = proposal_data.index.to_be_bytes();
+    check_seeds(pda_proposal, &pda::proposal_seeds(&index_bytes), proposal_data.bump, program_id)?;
+
+    Ok(proposal_data)
+}
@@ -0,0 +1,39 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::IntroReplyCounterZc;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::validators::check_seeds;
+
+// Read the reply count of `intro` in place rather than round-tripping it through Borsh
+pub fn load_reply_counter(
+    program_id: &Pubkey,
+    counter: &AccountInfo,
+    intro: &Pubkey
+) -> Result<u64, ProgramError> {
+    let counter_bytes = counter.data.borrow();
+    let counter_data = IntroReplyCounterZc::load(&counter_bytes)?;
+    if !counter_data.is_initialized() {
+        msg!("Reply Counter PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    if !counter_data.has_valid_discriminator() {
+        msg!("Account {} is not a reply counter", counter.key);
+        return Err(IntroError::ReplyCounterMismatch.into());
+    }
+
+    // Validate counter is the reply counter of intro
+    check_seeds(counter, &pda::reply_counter_seeds(intro), counter_data.bump(), program_id)
+        .map_err(|_| {
+            msg!("Reply counter {} does not belong to intro {}", counter.key, intro);
+            IntroError::ReplyCounterMismatch
+        })?;
+
+    Ok(counter_data.counter())
+}
@@ -0,0 +1,33 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::StakeAccount;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+use crate::utils::validators::{check_owner, check_seeds};
+
+// Load the stake account of `wallet`
+pub fn load_stake(
+    program_id: &Pubkey,
+    pda_stake: &AccountInfo,
+    wallet: &Pubkey
+) -> Result<StakeAccount, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(pda_stake, program_id)?;
+
+    let stake_data = decode_account::<StakeAccount>(pda_stake)?;
+
+    if !stake_data.is_initialized() || stake_data.discriminator != StakeAccount::DISCRIMINATOR {
+        msg!("Stake PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_seeds(pda_stake, &pda::stake_seeds(wallet), stake_data.bump, program_id)?;
+
+    Ok(stake_data)
+}
@@ -0,0 +1,25 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+};
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::load_stake::load_stake;
+
+// Stake tier of `wallet`, wallets that never staked pass their empty stake PDA and get tier 0
+pub fn load_stake_tier(
+    program_id: &Pubkey,
+    pda_stake: &AccountInfo,
+    wallet: &Pubkey
+) -> Result<u8, ProgramError> {
+    if pda_stake.owner != program_id || !is_account_initialized(pda_stake) {
+        msg!("No stake for {}, using tier 0", wallet);
+        return Ok(0);
+    }
+
+    let tier = load_stake(program_id, pda_stake, wallet)?.tier();
+    msg!("Stake tier {} for {}", tier, wallet);
+
+    Ok(tier)
+}
@@ -0,0 +1,33 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use crate::state::VestingAccount;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::decode_account::decode_account;
+use crate::utils::validators::{check_owner, check_seeds};
+
+// Load the vesting account of `wallet`
+pub fn load_vesting(
+    program_id: &Pubkey,
+    pda_vesting: &AccountInfo,
+    wallet: &Pubkey
+) -> Result<VestingAccount, ProgramError> {
+    // Validate that program owns PDA
+    check_owner(pda_vesting, program_id)?;
+
+    let vesting_data = decode_account::<VestingAccount>(pda_vesting)?;
+
+    if !vesting_data.is_initialized() || vesting_data.discriminator != VestingAccount::DISCRIMINATOR {
+        msg!("Vesting PDA is not initialized");
+        return Err(IntroError::UninitializedAccount.into());
+    }
+
+    check_seeds(pda_vesting, &pda::vesting_seeds(wallet), vesting_data.bump, program_id)?;
+
+    Ok(vesting_data)
+}
@@ -0,0 +1,85 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    msg,
+    account_info::AccountInfo,
+    program::{invoke, invoke_signed},
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token::state::Mint;
+use borsh::BorshSerialize;
+use crate::state::{Config, MintAuthorityMode};
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::validators::check_writable;
+
+// Mint `amount` reward tokens to `wallet`, creating its ATA paid by `payer` if needed
+// Every mint goes through here so `max_supply` is enforced against the mint supply and `total_minted` kept in Config
+// Accounts are expected to have been checked with `check_reward_accounts`
+#[allow(clippy::too_many_arguments)]
+pub fn mint_reward<'a>(
+    config: &mut Config,
+    pda_config: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    user_ata: &AccountInfo<'a>,
+    token_mint: &AccountInfo<'a>,
+    mint_auth: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+    amount: u64
+) -> ProgramResult {
+    if config.mint_authority_mode != MintAuthorityMode::Program {
+        msg!("Reward minting is unavailable, mint authority is {:?}", config.mint_authority_mode);
+        return Err(IntroError::MintAuthorityUnavailable.into());
+    }
+
+    check_writable(pda_config)?;
+
+    let supply = Mint::unpack(&token_mint.data.borrow())?.supply;
+    if let Err(error) = config.record_mint(supply, amount) {
+        msg!("Minting {} tokens would pass the max supply of {}", amount / LAMPORTS_PER_SOL, config.max_supply / LAMPORTS_PER_SOL);
+        return Err(error);
+    }
+    config.serialize(&mut &mut pda_config.data.borrow_mut()[..])?;
+
+    if !is_account_initialized(user_ata) {
+        msg!("ATA not created, creating...");
+        let instruction = create_associated_token_account(payer.key, wallet.key, token_mint.key, token_program.key);
+        invoke(
+            &instruction,
+            &[
+                payer.clone(),
+                user_ata.clone(),
+                wallet.clone(),
+                token_mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                associated_token_program.clone(),
+            ]
+        )?;
+        msg!("Created ATA for {}", wallet.key);
+    }
+
+    invoke_signed(
+        // Instruction
+        &spl_token::instruction::mint_to(
+            token_program.key,
+            token_mint.key,
+            user_ata.key,
+            mint_auth.key,
+            &[],
+            amount,
+        )?,
+        // Account_infos
+        &[token_mint.clone(), user_ata.clone(), mint_auth.clone()],
+        // Seeds
+        &[&[pda::TOKEN_AUTH_SEED, &[config.mint_auth_bump]]],
+    )?;
+    msg!("Minted {} tokens to {}, {} minted in total", amount / LAMPORTS_PER_SOL, wallet.key, config.total_minted / LAMPORTS_PER_SOL);
+
+    Ok(())
+}
@@ -1,2 +1,19 @@
 pub mod is_account_initialized;
-
+pub mod close_account;
+pub mod next_intro_index;
+pub mod load_community;
+pub mod create_pda_account;
+pub mod load_config;
+pub mod validators;
+pub mod load_intro;
+pub mod decode_account;
+pub mod load_reply_counter;
+pub mod create_reply_account;
+pub mod accrue_vesting;
+pub mod load_vesting;
+pub mod load_stake;
+pub mod load_stake_tier;
+pub mod load_bounty;
+pub mod release_bounty;
+pub mod mint_reward;
+pub mod load_proposal;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use solana_program::{
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    sysvar::rent::Rent,
+    program_error::ProgramError,
+    program_pack::IsInitialized,
+};
+use borsh::BorshSerialize;
+use crate::state::IntroCounter;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::is_account_initialized::is_account_initialized;
+use crate::utils::create_pda_account::create_pda_account;
+use crate::utils::validators::{check_owner, check_seeds, find_seeds};
+use crate::utils::decode_account::decode_account;
+
+// Claim the next intro index for `initializer`, creating its Intro Counter PDA on first use
+pub fn next_intro_index<'a>(
+    program_id: &Pubkey,
+    initializer: &AccountInfo<'a>,
+    pda_intro_counter: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &Rent
+) -> Result<u64, ProgramError> {
+    let pda_seed = pda::intro_counter_seeds(initializer.key);
+
+    let mut account_data = if !is_account_initialized(pda_intro_counter) {
+        // First intro from this wallet, create its counter
+        // Validate pda_intro_counter is expected
+        let bump_seed = find_seeds(pda_intro_counter, &pda_seed, program_id)?;
+
+        let account_len = IntroCounter::SIZE;
+
+        msg!("Creating Intro Counter PDA");
+        create_pda_account(
+            initializer,
+            pda_intro_counter,
+            system_program,
+            program_id,
+            rent.minimum_balance(account_len),
+            account_len,
+            &pda_seed,
+            bump_seed
+        )?;
+        msg!("Intro Counter PDA created: {}", pda_intro_counter.key);
+
+        IntroCounter {
+            discriminator: IntroCounter::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            count: 0,
+            bump: bump_seed
+        }
+    } else {
+        // Validate that program owns PDA
+        check_owner(pda_intro_counter, program_id)?;
+
+        let account_data = decode_account::<IntroCounter>(pda_intro_counter)?;
+
+        if !account_data.is_initialized() || account_data.discriminator != IntroCounter::DISCRIMINATOR {
+            msg!("Intro Counter PDA is not initialized");
+            return Err(IntroError::UninitializedAccount.into());
+        }
+
+        check_seeds(pda_intro_counter, &pda_seed, account_data.bump, program_id)?;
+
+        account_data
+    };
+
+    let index = account_data.count;
+    account_data.count = account_data.count.checked_add(1).ok_or(IntroError::ArithmeticOverflow)?;
+
+    account_data.serialize(&mut &mut pda_intro_counter.data.borrow_mut()[..])?;
+    msg!("Claimed intro index {} for {}", index, initializer.key);
+
+    Ok(index)
+}
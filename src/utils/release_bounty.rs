@@ -0,0 +1,72 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    msg,
+    account_info::AccountInfo,
+    program::invoke_signed,
+    program_pack::Pack,
+    native_token::LAMPORTS_PER_SOL,
+};
+use spl_token::state::Account as TokenAccount;
+use crate::state::Bounty;
+use crate::error::IntroError;
+use crate::pda;
+use crate::utils::close_account::close_account;
+
+// Pay the escrowed bounty to `recipient_ata` and anything else sent to the escrow to `introducer_ata`,
+// then close the escrow ATA and the bounty PDA, returning their rent to the introducer
+#[allow(clippy::too_many_arguments)]
+pub fn release_bounty<'a>(
+    bounty_data: &Bounty,
+    pda_bounty: &AccountInfo<'a>,
+    escrow_ata: &AccountInfo<'a>,
+    recipient_ata: &AccountInfo<'a>,
+    introducer_ata: &AccountInfo<'a>,
+    introducer: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>
+) -> ProgramResult {
+    let signer_seeds: &[&[u8]] = &[pda::BOUNTY_SEED, bounty_data.intro.as_ref(), &[bounty_data.bump]];
+    let transfer = |destination: &AccountInfo<'a>, amount: u64| invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            escrow_ata.key,
+            destination.key,
+            pda_bounty.key,
+            &[],
+            amount,
+        )?,
+        &[escrow_ata.clone(), destination.clone(), pda_bounty.clone()],
+        &[signer_seeds],
+    );
+
+    // The escrow ATA address is public, so anyone can top it up and it must be emptied before it can close
+    let balance = TokenAccount::unpack(&escrow_ata.data.borrow())?.amount;
+    let extra = balance.checked_sub(bounty_data.amount).ok_or(IntroError::ArithmeticOverflow)?;
+
+    if recipient_ata.key == introducer_ata.key {
+        transfer(recipient_ata, balance)?;
+    } else {
+        transfer(recipient_ata, bounty_data.amount)?;
+        if extra > 0 {
+            transfer(introducer_ata, extra)?;
+            msg!("Returned {} extra base units in escrow to introducer", extra);
+        }
+    }
+    msg!("Paid out {} token bounty", bounty_data.amount / LAMPORTS_PER_SOL);
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            escrow_ata.key,
+            introducer.key,
+            pda_bounty.key,
+            &[],
+        )?,
+        &[escrow_ata.clone(), introducer.clone(), pda_bounty.clone()],
+        &[signer_seeds],
+    )?;
+
+    close_account(pda_bounty, introducer)?;
+    msg!("Closed bounty escrow, rent returned to introducer");
+
+    Ok(())
+}
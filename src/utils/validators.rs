@@ -0,0 +1,311 @@
+// Reusable account checks shared by every `*Accounts::parse`
+
+use solana_program::{
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    msg,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_utils::limited_deserialize,
+    bpf_loader_upgradeable::{UpgradeableLoaderState, ID as BPF_LOADER_UPGRADEABLE_ID},
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use crate::state::Config;
+use crate::error::IntroError;
+use crate::pda;
+
+pub fn check_signer(account: &AccountInfo) -> ProgramResult {
+    if !account.is_signer {
+        msg!("Missing required signature for {}", account.key);
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+pub fn check_writable(account: &AccountInfo) -> ProgramResult {
+    if !account.is_writable {
+        msg!("Account {} must be writable", account.key);
+        return Err(IntroError::AccountNotWritable.into());
+    }
+    Ok(())
+}
+
+pub fn check_owner(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        msg!("Account {} is not owned by {}", account.key, owner);
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
+// For accounts that already exist and store their bump
+pub fn check_seeds(account: &AccountInfo, seeds: &[&[u8]], bump: u8, program_id: &Pubkey) -> ProgramResult {
+    pda::check_pda(seeds, bump, program_id, account.key)
+}
+
+// For accounts about to be created, returns the canonical bump to store
+pub fn find_seeds(account: &AccountInfo, seeds: &[&[u8]], program_id: &Pubkey) -> Result<u8, ProgramError> {
+    let (pda, bump_seed) = Pubkey::find_program_address(seeds, program_id);
+    if pda != *account.key {
+        msg!("Invalid seeds for PDA {}", account.key);
+        return Err(IntroError::InvalidPDA.into());
+    }
+    Ok(bump_seed)
+}
+
+pub fn check_ata(account: &AccountInfo, wallet: &Pubkey, mint: &Pubkey) -> ProgramResult {
+    if *account.key != get_associated_token_address(wallet, mint) {
+        msg!("Account {} is not the associated token account of {}", account.key, wallet);
+        return Err(IntroError::IncorrectAccountError.into());
+    }
+    Ok(())
+}
+
+pub fn check_program_id(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    if account.key != program_id {
+        msg!("Expected program {}, got {}", program_id, account.key);
+        return Err(IntroError::IncorrectProgramId.into());
+    }
+    Ok(())
+}
+
+// Mint, mint authority, recipient ATA and token program used by every reward mint
+pub fn check_reward_accounts(
+    program_id: &Pubkey,
+    config: &Config,
+    token_mint: &AccountInfo,
+    mint_auth: &AccountInfo,
+    user_ata: &AccountInfo,
+    wallet: &Pubkey,
+    token_program: &AccountInfo
+) -> ProgramResult {
+    check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+    check_writable(token_mint)?;
+    check_seeds(mint_auth, &pda::token_auth_seeds(), config.mint_auth_bump, program_id)?;
+    check_ata(user_ata, wallet, token_mint.key)?;
+    check_writable(user_ata)?;
+    check_program_id(token_program, &TOKEN_PROGRAM_ID)
+}
+
+// Mint, vault authority, vault ATA and token program used by every stake transfer, returns the vault authority bump
+pub fn check_stake_vault_accounts(
+    program_id: &Pubkey,
+    config: &Config,
+    token_mint: &AccountInfo,
+    vault_auth: &AccountInfo,
+    vault_ata: &AccountInfo,
+    token_program: &AccountInfo
+) -> Result<u8, ProgramError> {
+    check_seeds(token_mint, &pda::token_mint_seeds(), config.mint_bump, program_id)?;
+    check_seeds(vault_auth, &pda::stake_vault_seeds(), config.stake_vault_bump, program_id)?;
+    check_ata(vault_ata, vault_auth.key, token_mint.key)?;
+    check_writable(vault_ata)?;
+    check_program_id(token_program, &TOKEN_PROGRAM_ID)?;
+    Ok(config.stake_vault_bump)
+}
+
+// Config is set up by the program's upgrade authority, read from its ProgramData account
+pub fn check_upgrade_authority(program_id: &Pubkey, program_data: &AccountInfo, authority: &Pubkey) -> ProgramResult {
+    let (program_data_address, _) = Pubkey::find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if *program_data.key != program_data_address {
+        msg!("Account {} is not the ProgramData account of {}", program_data.key, program_id);
+        return Err(IntroError::IncorrectAccountError.into());
+    }
+    check_owner(program_data, &BPF_LOADER_UPGRADEABLE_ID)?;
+
+    let data = program_data.data.borrow();
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let metadata = data.get(..metadata_len).ok_or(ProgramError::InvalidAccountData)?;
+    match limited_deserialize::<UpgradeableLoaderState>(metadata, metadata_len as u64) {
+        Ok(UpgradeableLoaderState::ProgramData { upgrade_authority_address: Some(upgrade_authority), .. }) if upgrade_authority == *authority => Ok(()),
+        _ => {
+            msg!("{} is not the upgrade authority of {}", authority, program_id);
+            Err(IntroError::Unauthorized.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::bpf_loader_upgradeable;
+
+    // Owns the key, lamports and data an AccountInfo borrows
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey) -> Self {
+            TestAccount { key, owner: Pubkey::default(), lamports: 0, data: Vec::new() }
+        }
+
+        fn info(&mut self, is_signer: bool, is_writable: bool) -> AccountInfo<'_> {
+            AccountInfo::new(&self.key, is_signer, is_writable, &mut self.lamports, &mut self.data, &self.owner, false, 0)
+        }
+    }
+
+    fn test_config(program_id: &Pubkey) -> Config {
+        Config {
+            discriminator: Config::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            admin: Pubkey::new_unique(),
+            mint_bump: Pubkey::find_program_address(&pda::token_mint_seeds(), program_id).1,
+            mint_auth_bump: Pubkey::find_program_address(&pda::token_auth_seeds(), program_id).1,
+            stake_vault_bump: Pubkey::find_program_address(&pda::stake_vault_seeds(), program_id).1,
+            bump: Pubkey::find_program_address(&pda::config_seeds(), program_id).1,
+            referral_reward: 0,
+            max_supply: 0,
+            total_minted: 0,
+            mint_authority_mode: crate::state::MintAuthorityMode::Program,
+            intro_reward: None,
+            reply_reward: None,
+            max_message_bytes: None,
+            proposal_count: 0,
+        }
+    }
+
+    fn custom(error: IntroError) -> ProgramError {
+        error.into()
+    }
+
+    #[test]
+    fn signer_writable_and_owner() {
+        let mut account = TestAccount::new(Pubkey::new_unique());
+        assert_eq!(check_signer(&account.info(false, true)), Err(ProgramError::MissingRequiredSignature));
+        assert_eq!(check_signer(&account.info(true, false)), Ok(()));
+        assert_eq!(check_writable(&account.info(true, false)), Err(custom(IntroError::AccountNotWritable)));
+        assert_eq!(check_writable(&account.info(false, true)), Ok(()));
+
+        let owner = Pubkey::new_unique();
+        assert_eq!(check_owner(&account.info(false, false), &owner), Err(ProgramError::IllegalOwner));
+        account.owner = owner;
+        assert_eq!(check_owner(&account.info(false, false), &owner), Ok(()));
+    }
+
+    #[test]
+    fn seeds() {
+        let program_id = Pubkey::new_unique();
+        let (config_key, bump) = Pubkey::find_program_address(&pda::config_seeds(), &program_id);
+
+        let mut account = TestAccount::new(config_key);
+        assert_eq!(find_seeds(&account.info(false, false), &pda::config_seeds(), &program_id), Ok(bump));
+        assert_eq!(check_seeds(&account.info(false, false), &pda::config_seeds(), bump, &program_id), Ok(()));
+
+        let mut other = TestAccount::new(Pubkey::new_unique());
+        assert_eq!(find_seeds(&other.info(false, false), &pda::config_seeds(), &program_id), Err(custom(IntroError::InvalidPDA)));
+        assert_eq!(check_seeds(&other.info(false, false), &pda::config_seeds(), bump, &program_id), Err(custom(IntroError::InvalidPDA)));
+        // Wrong bump for the right address
+        assert_eq!(check_seeds(&account.info(false, false), &pda::config_seeds(), bump.wrapping_sub(1), &program_id), Err(custom(IntroError::InvalidPDA)));
+    }
+
+    #[test]
+    fn ata_and_program_id() {
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut ata = TestAccount::new(get_associated_token_address(&wallet, &mint));
+        assert_eq!(check_ata(&ata.info(false, true), &wallet, &mint), Ok(()));
+        assert_eq!(check_ata(&ata.info(false, true), &Pubkey::new_unique(), &mint), Err(custom(IntroError::IncorrectAccountError)));
+
+        let mut token_program = TestAccount::new(TOKEN_PROGRAM_ID);
+        assert_eq!(check_program_id(&token_program.info(false, false), &TOKEN_PROGRAM_ID), Ok(()));
+        let mut fake_program = TestAccount::new(Pubkey::new_unique());
+        assert_eq!(check_program_id(&fake_program.info(false, false), &TOKEN_PROGRAM_ID), Err(custom(IntroError::IncorrectProgramId)));
+    }
+
+    #[test]
+    fn reward_accounts() {
+        let program_id = Pubkey::new_unique();
+        let config = test_config(&program_id);
+        let wallet = Pubkey::new_unique();
+        let mint_key = Pubkey::find_program_address(&pda::token_mint_seeds(), &program_id).0;
+
+        let mut mint = TestAccount::new(mint_key);
+        let mut mint_auth = TestAccount::new(Pubkey::find_program_address(&pda::token_auth_seeds(), &program_id).0);
+        let mut ata = TestAccount::new(get_associated_token_address(&wallet, &mint_key));
+        let mut token_program = TestAccount::new(TOKEN_PROGRAM_ID);
+        let mut fake = TestAccount::new(Pubkey::new_unique());
+
+        let check = |mint: &AccountInfo, mint_auth: &AccountInfo, ata: &AccountInfo, token_program: &AccountInfo| {
+            check_reward_accounts(&program_id, &config, mint, mint_auth, ata, &wallet, token_program)
+        };
+
+        assert_eq!(check(&mint.info(false, true), &mint_auth.info(false, false), &ata.info(false, true), &token_program.info(false, false)), Ok(()));
+        assert_eq!(check(&fake.info(false, true), &mint_auth.info(false, false), &ata.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::InvalidPDA)));
+        assert_eq!(check(&mint.info(false, false), &mint_auth.info(false, false), &ata.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::AccountNotWritable)));
+        assert_eq!(check(&mint.info(false, true), &fake.info(false, false), &ata.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::InvalidPDA)));
+        assert_eq!(check(&mint.info(false, true), &mint_auth.info(false, false), &fake.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::IncorrectAccountError)));
+        assert_eq!(check(&mint.info(false, true), &mint_auth.info(false, false), &ata.info(false, false), &token_program.info(false, false)), Err(custom(IntroError::AccountNotWritable)));
+        assert_eq!(check(&mint.info(false, true), &mint_auth.info(false, false), &ata.info(false, true), &fake.info(false, false)), Err(custom(IntroError::IncorrectProgramId)));
+    }
+
+    #[test]
+    fn stake_vault_accounts() {
+        let program_id = Pubkey::new_unique();
+        let config = test_config(&program_id);
+        let mint_key = Pubkey::find_program_address(&pda::token_mint_seeds(), &program_id).0;
+        let vault_key = Pubkey::find_program_address(&pda::stake_vault_seeds(), &program_id).0;
+
+        let mut mint = TestAccount::new(mint_key);
+        let mut vault_auth = TestAccount::new(vault_key);
+        let mut vault_ata = TestAccount::new(get_associated_token_address(&vault_key, &mint_key));
+        let mut token_program = TestAccount::new(TOKEN_PROGRAM_ID);
+        let mut fake = TestAccount::new(Pubkey::new_unique());
+
+        let check = |mint: &AccountInfo, vault_auth: &AccountInfo, vault_ata: &AccountInfo, token_program: &AccountInfo| {
+            check_stake_vault_accounts(&program_id, &config, mint, vault_auth, vault_ata, token_program)
+        };
+
+        assert_eq!(check(&mint.info(false, false), &vault_auth.info(false, false), &vault_ata.info(false, true), &token_program.info(false, false)), Ok(config.stake_vault_bump));
+        assert_eq!(check(&fake.info(false, false), &vault_auth.info(false, false), &vault_ata.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::InvalidPDA)));
+        assert_eq!(check(&mint.info(false, false), &fake.info(false, false), &vault_ata.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::InvalidPDA)));
+        assert_eq!(check(&mint.info(false, false), &vault_auth.info(false, false), &fake.info(false, true), &token_program.info(false, false)), Err(custom(IntroError::IncorrectAccountError)));
+        assert_eq!(check(&mint.info(false, false), &vault_auth.info(false, false), &vault_ata.info(false, false), &token_program.info(false, false)), Err(custom(IntroError::AccountNotWritable)));
+        assert_eq!(check(&mint.info(false, false), &vault_auth.info(false, false), &vault_ata.info(false, true), &fake.info(false, false)), Err(custom(IntroError::IncorrectProgramId)));
+    }
+
+    #[test]
+    fn upgrade_authority() {
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let program_data_key = Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0;
+
+        // ProgramData metadata: enum tag, deployment slot, then the optional upgrade authority
+        let metadata = |upgrade_authority: Option<Pubkey>| {
+            let mut data = 3u32.to_le_bytes().to_vec();
+            data.extend_from_slice(&0u64.to_le_bytes());
+            match upgrade_authority {
+                Some(key) => {
+                    data.push(1);
+                    data.extend_from_slice(key.as_ref());
+                },
+                None => data.extend_from_slice(&[0; 33]),
+            }
+            data
+        };
+
+        let mut program_data = TestAccount::new(program_data_key);
+        program_data.owner = bpf_loader_upgradeable::id();
+        program_data.data = metadata(Some(authority));
+        assert_eq!(check_upgrade_authority(&program_id, &program_data.info(false, false), &authority), Ok(()));
+        assert_eq!(check_upgrade_authority(&program_id, &program_data.info(false, false), &Pubkey::new_unique()), Err(custom(IntroError::Unauthorized)));
+
+        // Immutable programs have no upgrade authority
+        program_data.data = metadata(None);
+        assert_eq!(check_upgrade_authority(&program_id, &program_data.info(false, false), &authority), Err(custom(IntroError::Unauthorized)));
+
+        program_data.data.truncate(4);
+        assert_eq!(check_upgrade_authority(&program_id, &program_data.info(false, false), &authority), Err(ProgramError::InvalidAccountData));
+
+        program_data.data = metadata(Some(authority));
+        program_data.owner = Pubkey::new_unique();
+        assert_eq!(check_upgrade_authority(&program_id, &program_data.info(false, false), &authority), Err(ProgramError::IllegalOwner));
+
+        let mut other = TestAccount::new(Pubkey::new_unique());
+        assert_eq!(check_upgrade_authority(&program_id, &other.info(false, false), &authority), Err(custom(IntroError::IncorrectAccountError)));
+    }
+}
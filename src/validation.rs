@@ -0,0 +1,109 @@
+// Content rules for user-supplied text, applied before anything is written on-chain
+
+use solana_program::msg;
+use unicode_normalization::is_nfc;
+use unicode_segmentation::UnicodeSegmentation;
+use crate::error::IntroError;
+
+// Per-field limits, graphemes bound what users see and bytes bound what they pay rent for
+pub struct FieldLimits {
+    pub field: &'static str,
+    pub max_graphemes: usize,
+    pub max_bytes: usize,
+    pub allow_newlines: bool,
+}
+
+impl FieldLimits {
+    // Stake tiers raise the length limits, tier 0 keeps them as they are
+    pub fn for_stake_tier(&self, tier: u8) -> FieldLimits {
+        let multiplier = 1 + tier as usize;
+        FieldLimits {
+            field: self.field,
+            max_graphemes: self.max_graphemes * multiplier,
+            max_bytes: self.max_bytes * multiplier,
+            allow_newlines: self.allow_newlines,
+        }
+    }
+}
+
+pub const NAME_LIMITS: FieldLimits = FieldLimits {
+    field: "name",
+    max_graphemes: 50,
+    max_bytes: 200,
+    allow_newlines: false,
+};
+
+pub const MESSAGE_LIMITS: FieldLimits = FieldLimits {
+    field: "message",
+    max_graphemes: 500,
+    max_bytes: 800,
+    allow_newlines: true,
+};
+
+pub const REPLY_LIMITS: FieldLimits = FieldLimits {
+    field: "reply",
+    max_graphemes: 500,
+    max_bytes: 800,
+    allow_newlines: true,
+};
+
+// Upper bound on the governed message and reply byte limit, before stake tiers are applied
+pub const MAX_MESSAGE_BYTES: usize = 2_000;
+
+// Off-chain content URIs stored in place of a message or reply
+pub const CONTENT_URI_LIMITS: FieldLimits = FieldLimits {
+    field: "content URI",
+    max_graphemes: 200,
+    max_bytes: 200,
+    allow_newlines: false,
+};
+
+// Zero-width, word-joiner, BOM and bidirectional override characters that hide or reorder text
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+pub fn validate_text(text: &str, limits: &FieldLimits) -> Result<(), IntroError> {
+    if text.trim().is_empty() {
+        msg!("{} is empty", limits.field);
+        return Err(IntroError::EmptyContent);
+    }
+
+    if text.len() > limits.max_bytes {
+        msg!("{} is longer than {} bytes", limits.field, limits.max_bytes);
+        return Err(IntroError::ContentTooLong);
+    }
+
+    for c in text.chars() {
+        if c.is_control() && !(limits.allow_newlines && c == '\n') {
+            msg!("{} contains control character {:?}", limits.field, c);
+            return Err(IntroError::ControlCharacter);
+        }
+
+        if is_invisible(c) {
+            msg!("{} contains invisible character {:?}", limits.field, c);
+            return Err(IntroError::InvisibleCharacter);
+        }
+    }
+
+    // Stored text must already be NFC so the same visible string always has the same bytes
+    if !is_nfc(text) {
+        msg!("{} is not NFC normalized", limits.field);
+        return Err(IntroError::NotNormalized);
+    }
+
+    if text.graphemes(true).count() > limits.max_graphemes {
+        msg!("{} is longer than {} characters", limits.field, limits.max_graphemes);
+        return Err(IntroError::ContentTooLong);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{instruction_data, TestEnv};
+use solana_hello_world::state::{Config, VestingAccount};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+
+fn add_replies_ix(env: &TestEnv, replier: &Pubkey, replier_signs: bool, intro: &Pubkey, replies: &[&str]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(env.admin.pubkey(), true),
+        AccountMeta::new_readonly(*replier, replier_signs),
+        AccountMeta::new_readonly(*intro, false),
+        AccountMeta::new(env.reply_counter(intro), false),
+        AccountMeta::new(env.vesting(replier), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(env.config(), false),
+    ];
+    accounts.extend((0..replies.len() as u64).map(|counter| AccountMeta::new(env.reply(intro, counter), false)));
+    let replies: Vec<String> = replies.iter().map(|reply| reply.to_string()).collect();
+    Instruction::new_with_bytes(env.program_id, &instruction_data(16, &replies), accounts)
+}
+
+#[tokio::test]
+async fn import_requires_the_author_signature() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let admin = env.admin.insecure_clone();
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let replier = env.funded_wallet().await;
+
+    // The admin alone cannot post replies under another wallet
+    let instruction = add_replies_ix(&env, &replier.pubkey(), false, &intro, &["Hi", "Welcome!"]);
+    let result = env.send(&[instruction], &[&admin]).await;
+    assert_eq!(common::instruction_error(result), InstructionError::MissingRequiredSignature);
+
+    let instruction = add_replies_ix(&env, &replier.pubkey(), true, &intro, &["Hi", "Welcome!"]);
+    env.send(&[instruction], &[&admin, &replier]).await.unwrap();
+    // Both replies are rewarded through a single vesting accrual
+    let vesting = VestingAccount::try_from_slice(&env.account_data(&env.vesting(&replier.pubkey())).await).unwrap();
+    assert_eq!(vesting.locked(), Ok(2 * Config::DEFAULT_REPLY_REWARD));
+}
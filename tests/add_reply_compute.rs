@@ -0,0 +1,35 @@
+// Compute units used by AddReply, the path the zero-copy reply counter was introduced for
+// Native processors are not metered, so this only runs against the SBF build:
+//     cargo build-sbf && cargo test-sbf --features test-sbf --test add_reply_compute -- --nocapture
+// Run the same test on the commit before the zero-copy counter to get the Borsh baseline
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+// AddReply, including its Reply, bond and vesting account creation, stays within this budget
+const ADD_REPLY_CU_BUDGET: u64 = 60_000;
+
+#[tokio::test]
+async fn add_reply_compute_units() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+
+    let replier = env.funded_wallet().await;
+    let mut units = Vec::new();
+    for counter in 0..3 {
+        let instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), counter, "Welcome!");
+        units.push(env.simulate_units(std::slice::from_ref(&instruction), &[&replier]).await);
+        env.send(&[instruction], &[&replier]).await.unwrap();
+    }
+    println!("AddReply compute units (first reply, then later replies): {units:?}");
+
+    for used in units {
+        assert!(used <= ADD_REPLY_CU_BUDGET, "AddReply used {used} compute units");
+    }
+}
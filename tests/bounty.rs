@@ -0,0 +1,166 @@
+mod common;
+
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+const BOUNTY: u64 = 50 * LAMPORTS_PER_SOL;
+const EXPIRY_SLOT: u64 = 1_000;
+
+fn bounty(env: &TestEnv, intro: &Pubkey) -> Pubkey {
+    find_pda(&pda::bounty_seeds(intro), &env.program_id)
+}
+
+fn escrow(env: &TestEnv, intro: &Pubkey) -> Pubkey {
+    env.ata(&bounty(env, intro))
+}
+
+fn create_bounty_ix(env: &TestEnv, introducer: &Pubkey, intro: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(23, &(BOUNTY, EXPIRY_SLOT)),
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new_readonly(*intro, false),
+            AccountMeta::new(bounty(env, intro), false),
+            AccountMeta::new(env.ata(introducer), false),
+            AccountMeta::new(escrow(env, intro), false),
+            AccountMeta::new_readonly(env.token_mint(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(env.config(), false),
+        ],
+    )
+}
+
+fn award_bounty_ix(env: &TestEnv, introducer: &Pubkey, intro: &Pubkey, reply: &Pubkey, replier: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &[24],
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new_readonly(*intro, false),
+            AccountMeta::new(bounty(env, intro), false),
+            AccountMeta::new_readonly(*reply, false),
+            AccountMeta::new_readonly(*replier, false),
+            AccountMeta::new(env.ata(replier), false),
+            AccountMeta::new(env.ata(introducer), false),
+            AccountMeta::new(escrow(env, intro), false),
+            AccountMeta::new_readonly(env.token_mint(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(env.config(), false),
+        ],
+    )
+}
+
+fn cancel_bounty_ix(env: &TestEnv, introducer: &Pubkey, intro: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &[25],
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new_readonly(*intro, false),
+            AccountMeta::new(bounty(env, intro), false),
+            AccountMeta::new(env.ata(introducer), false),
+            AccountMeta::new(escrow(env, intro), false),
+            AccountMeta::new_readonly(env.token_mint(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(env.config(), false),
+        ],
+    )
+}
+
+// Funded introducer with an intro and BOUNTY tokens to escrow
+async fn introducer_with_tokens(env: &mut TestEnv) -> (Keypair, Pubkey) {
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    env.airdrop(&introducer.pubkey(), BOUNTY).await;
+    (introducer, intro)
+}
+
+#[tokio::test]
+async fn award_pays_the_replier_after_escrow_griefing() {
+    let mut env = common::start().await;
+    let (introducer, intro) = introducer_with_tokens(&mut env).await;
+
+    // Anyone can create the escrow ATA before the bounty exists and send tokens to it
+    let payer = env.payer();
+    let precreate = create_associated_token_account_idempotent(&payer.pubkey(), &bounty(&env, &intro), &env.token_mint(), &spl_token::id());
+    env.send(&[precreate], &[]).await.unwrap();
+    env.airdrop(&bounty(&env, &intro), 1).await;
+
+    let instruction = create_bounty_ix(&env, &introducer.pubkey(), &intro);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+    assert_eq!(env.token_balance(&escrow(&env, &intro)).await, Some(BOUNTY + 1));
+    assert_eq!(env.token_balance(&env.ata(&introducer.pubkey())).await, Some(0));
+
+    let replier = env.funded_wallet().await;
+    let instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), 0, "Welcome!");
+    env.send(&[instruction], &[&replier]).await.unwrap();
+    env.airdrop(&bounty(&env, &intro), 1).await;
+
+    let reply = env.reply(&intro, 0);
+    let instruction = award_bounty_ix(&env, &introducer.pubkey(), &intro, &reply, &replier.pubkey());
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+
+    assert_eq!(env.token_balance(&env.ata(&replier.pubkey())).await, Some(BOUNTY));
+    assert_eq!(env.token_balance(&env.ata(&introducer.pubkey())).await, Some(2));
+    assert_eq!(env.token_balance(&escrow(&env, &intro)).await, None);
+    assert!(env.context.banks_client.get_account(bounty(&env, &intro)).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn cancel_returns_the_whole_escrow() {
+    let mut env = common::start().await;
+    let (introducer, intro) = introducer_with_tokens(&mut env).await;
+
+    let instruction = create_bounty_ix(&env, &introducer.pubkey(), &intro);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+    env.airdrop(&bounty(&env, &intro), 1).await;
+
+    let instruction = cancel_bounty_ix(&env, &introducer.pubkey(), &intro);
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::BountyNotExpired));
+
+    env.context.warp_to_slot(EXPIRY_SLOT).unwrap();
+    let instruction = cancel_bounty_ix(&env, &introducer.pubkey(), &intro);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+
+    assert_eq!(env.token_balance(&env.ata(&introducer.pubkey())).await, Some(BOUNTY + 1));
+    assert_eq!(env.token_balance(&escrow(&env, &intro)).await, None);
+    assert!(env.context.banks_client.get_account(bounty(&env, &intro)).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn only_the_introducer_creates_a_bounty() {
+    let mut env = common::start().await;
+    let (introducer, intro) = introducer_with_tokens(&mut env).await;
+    let other = env.funded_wallet().await;
+    env.airdrop(&other.pubkey(), BOUNTY).await;
+
+    let instruction = create_bounty_ix(&env, &other.pubkey(), &intro);
+    let result = env.send(&[instruction], &[&other]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::Unauthorized));
+
+    // The introducer cannot award a bounty to their own reply
+    let instruction = create_bounty_ix(&env, &introducer.pubkey(), &intro);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+    let instruction = env.add_reply_ix(&introducer.pubkey(), &intro, &introducer.pubkey(), 0, "Self reply");
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+    let instruction = award_bounty_ix(&env, &introducer.pubkey(), &intro, &env.reply(&intro, 0), &introducer.pubkey());
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::SelfAward));
+}
@@ -0,0 +1,303 @@
+// Shared program-test setup, each test binary uses a different subset
+#![allow(dead_code)]
+
+use borsh::BorshSerialize;
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::StakeAccount;
+use solana_hello_world::processor::process_instruction;
+use spl_associated_token_account::get_associated_token_address;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    program_pack::Pack,
+    system_instruction, system_program, sysvar,
+    native_token::LAMPORTS_PER_SOL,
+    transaction::{Transaction, TransactionError},
+};
+
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+    pub program_id: Pubkey,
+    // Upgrade authority of the program, which InitializeMint makes the Config admin
+    pub admin: Keypair,
+}
+
+pub async fn start() -> TestEnv {
+    let program_id = Pubkey::new_unique();
+    let admin = Keypair::new();
+    let mut program_test = ProgramTest::new("solana_hello_world", program_id, processor!(process_instruction));
+    // Load the built .so so compute units are metered, rather than the native processor
+    #[cfg(feature = "test-sbf")]
+    {
+        // program-test silently falls back to the native processor when the .so is missing
+        let out_dir = std::env::var("SBF_OUT_DIR").or_else(|_| std::env::var("BPF_OUT_DIR")).expect("run through cargo test-sbf");
+        assert!(std::path::Path::new(&out_dir).join("solana_hello_world.so").exists(), "build the program with cargo build-sbf first");
+        program_test.prefer_bpf(true);
+    }
+
+    // program-test loads programs as non-upgradeable, so add the ProgramData account InitializeMint reads
+    let program_data = UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address: Some(admin.pubkey()) };
+    program_test.add_account(program_data_address(&program_id), Account {
+        lamports: LAMPORTS_PER_SOL,
+        data: bincode::serialize(&program_data).unwrap(),
+        owner: bpf_loader_upgradeable::id(),
+        executable: false,
+        rent_epoch: 0,
+    });
+    program_test.add_account(admin.pubkey(), Account::new(100 * LAMPORTS_PER_SOL, 0, &system_program::id()));
+
+    let context = program_test.start_with_context().await;
+    TestEnv { context, program_id, admin }
+}
+
+pub fn program_data_address(program_id: &Pubkey) -> Pubkey {
+    find_pda(&[program_id.as_ref()], &bpf_loader_upgradeable::id())
+}
+
+pub fn find_pda(seeds: &[&[u8]], program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(seeds, program_id).0
+}
+
+// Instruction data is the variant byte followed by the Borsh-encoded payload
+pub fn instruction_data<T: BorshSerialize>(variant: u8, payload: &T) -> Vec<u8> {
+    let mut data = vec![variant];
+    payload.serialize(&mut data).unwrap();
+    data
+}
+
+impl TestEnv {
+    pub fn payer(&self) -> Keypair {
+        self.context.payer.insecure_clone()
+    }
+
+    pub fn config(&self) -> Pubkey {
+        find_pda(&pda::config_seeds(), &self.program_id)
+    }
+
+    pub fn token_mint(&self) -> Pubkey {
+        find_pda(&pda::token_mint_seeds(), &self.program_id)
+    }
+
+    pub fn token_auth(&self) -> Pubkey {
+        find_pda(&pda::token_auth_seeds(), &self.program_id)
+    }
+
+    pub fn intro(&self, introducer: &Pubkey, index: u64) -> Pubkey {
+        find_pda(&pda::intro_seeds(&None, introducer, &index.to_be_bytes()), &self.program_id)
+    }
+
+    pub fn reply_counter(&self, intro: &Pubkey) -> Pubkey {
+        find_pda(&pda::reply_counter_seeds(intro), &self.program_id)
+    }
+
+    pub fn reply(&self, intro: &Pubkey, counter: u64) -> Pubkey {
+        find_pda(&pda::reply_seeds(intro, &counter.to_be_bytes()), &self.program_id)
+    }
+
+    pub fn vesting(&self, wallet: &Pubkey) -> Pubkey {
+        find_pda(&pda::vesting_seeds(wallet), &self.program_id)
+    }
+
+    pub fn stake(&self, wallet: &Pubkey) -> Pubkey {
+        find_pda(&pda::stake_seeds(wallet), &self.program_id)
+    }
+
+    pub fn profile(&self, wallet: &Pubkey) -> Pubkey {
+        find_pda(&pda::profile_seeds(wallet), &self.program_id)
+    }
+
+    // Overwrite the Borsh data of a program account, creating it if needed, to seed state instructions cannot reach
+    pub async fn set_program_account<T: BorshSerialize>(&mut self, address: &Pubkey, data: &T, len: usize) {
+        let mut bytes = data.try_to_vec().unwrap();
+        bytes.resize(len, 0);
+        let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, len, &self.program_id);
+        account.set_data_from_slice(&bytes);
+        self.context.set_account(address, &account);
+    }
+
+    // Seed the stake account of `wallet` with `amount` staked, without moving tokens into the vault
+    pub async fn set_stake(&mut self, wallet: &Pubkey, amount: u64) {
+        let (stake_key, bump) = Pubkey::find_program_address(&pda::stake_seeds(wallet), &self.program_id);
+        let stake = StakeAccount {
+            discriminator: StakeAccount::DISCRIMINATOR.to_string(),
+            is_initialized: true,
+            wallet: *wallet,
+            amount,
+            unbonding_amount: 0,
+            unbonding_end_slot: 0,
+            bump
+        };
+        self.set_program_account(&stake_key, &stake, StakeAccount::SIZE).await;
+    }
+
+    pub async fn account_data(&mut self, address: &Pubkey) -> Vec<u8> {
+        self.context.banks_client.get_account(*address).await.unwrap().unwrap().data
+    }
+
+    // New wallet funded by the payer
+    pub async fn funded_wallet(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        let payer = self.payer();
+        let transfer = system_instruction::transfer(&payer.pubkey(), &wallet.pubkey(), 10 * LAMPORTS_PER_SOL);
+        self.send(&[transfer], &[]).await.unwrap();
+        wallet
+    }
+
+    // Send `instructions` paid for by the payer, with `signers` signing alongside it
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    // Compute units `instructions` consume, from a simulation that is not committed
+    pub async fn simulate_units(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> u64 {
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.context.banks_client.get_latest_blockhash().await.unwrap();
+        let transaction = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+        let simulation = self.context.banks_client.simulate_transaction(transaction).await.unwrap();
+        simulation.result.unwrap().unwrap();
+        simulation.simulation_details.unwrap().units_consumed
+    }
+
+    pub fn initialize_mint_ix(&self, initializer: &Pubkey) -> Instruction {
+        Instruction::new_with_bytes(
+            self.program_id,
+            &[3],
+            vec![
+                AccountMeta::new(*initializer, true),
+                AccountMeta::new(self.token_mint(), false),
+                AccountMeta::new_readonly(self.token_auth(), false),
+                AccountMeta::new(self.config(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new_readonly(program_data_address(&self.program_id), false),
+            ],
+        )
+    }
+
+    pub async fn initialize_mint(&mut self) {
+        let admin = self.admin.insecure_clone();
+        let instruction = self.initialize_mint_ix(&admin.pubkey());
+        self.send(&[instruction], &[&admin]).await.unwrap();
+    }
+
+    // Admin Airdrop of `amount` base units to `wallet`, creating its ATA if needed
+    pub fn airdrop_ix(&self, wallet: &Pubkey, amount: u64) -> Instruction {
+        Instruction::new_with_bytes(
+            self.program_id,
+            &instruction_data(27, &vec![amount]),
+            vec![
+                AccountMeta::new(self.admin.pubkey(), true),
+                AccountMeta::new(self.config(), false),
+                AccountMeta::new(self.token_mint(), false),
+                AccountMeta::new_readonly(self.token_auth(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(*wallet, false),
+                AccountMeta::new(self.ata(wallet), false),
+            ],
+        )
+    }
+
+    pub async fn airdrop(&mut self, wallet: &Pubkey, amount: u64) {
+        let admin = self.admin.insecure_clone();
+        let instruction = self.airdrop_ix(wallet, amount);
+        self.send(&[instruction], &[&admin]).await.unwrap();
+    }
+
+    // Reward token ATA of `wallet`
+    pub fn ata(&self, wallet: &Pubkey) -> Pubkey {
+        get_associated_token_address(wallet, &self.token_mint())
+    }
+
+    // Token balance of `ata`, None once it is closed
+    pub async fn token_balance(&mut self, ata: &Pubkey) -> Option<u64> {
+        let account = self.context.banks_client.get_account(*ata).await.unwrap()?;
+        Some(spl_token::state::Account::unpack(&account.data).unwrap().amount)
+    }
+
+    // AddIntro for an unscoped intro at `index` without a referrer
+    pub fn add_intro_ix(&self, introducer: &Pubkey, index: u64, name: &str, message: &str) -> Instruction {
+        let intro = self.intro(introducer, index);
+        Instruction::new_with_bytes(
+            self.program_id,
+            &instruction_data(0, &(name.to_string(), message.to_string(), None::<Pubkey>, None::<Pubkey>)),
+            vec![
+                AccountMeta::new(*introducer, true),
+                AccountMeta::new(find_pda(&pda::intro_counter_seeds(introducer), &self.program_id), false),
+                AccountMeta::new(intro, false),
+                AccountMeta::new(self.reply_counter(&intro), false),
+                AccountMeta::new(self.vesting(introducer), false),
+                AccountMeta::new_readonly(self.stake(introducer), false),
+                AccountMeta::new_readonly(self.config(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    }
+
+    pub async fn add_intro(&mut self, introducer: &Keypair, index: u64) -> Pubkey {
+        let instruction = self.add_intro_ix(&introducer.pubkey(), index, "Alice", "Hello world");
+        self.send(&[instruction], &[introducer]).await.unwrap();
+        self.intro(&introducer.pubkey(), index)
+    }
+
+    pub async fn create_profile(&mut self, wallet: &Keypair) {
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &instruction_data(6, &("Alice".to_string(), String::new(), String::new())),
+            vec![
+                AccountMeta::new(wallet.pubkey(), true),
+                AccountMeta::new(self.profile(&wallet.pubkey()), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        );
+        self.send(&[instruction], &[wallet]).await.unwrap();
+    }
+
+    // AddReply as reply number `counter` to `intro`, owned by `introducer`
+    pub fn add_reply_ix(&self, replier: &Pubkey, intro: &Pubkey, introducer: &Pubkey, counter: u64, reply: &str) -> Instruction {
+        let pda_reply = self.reply(intro, counter);
+        Instruction::new_with_bytes(
+            self.program_id,
+            &instruction_data(2, &reply.to_string()),
+            vec![
+                AccountMeta::new(*replier, true),
+                AccountMeta::new_readonly(*intro, false),
+                AccountMeta::new(self.reply_counter(intro), false),
+                AccountMeta::new(pda_reply, false),
+                AccountMeta::new(find_pda(&pda::bond_seeds(&pda_reply), &self.program_id), false),
+                AccountMeta::new(self.profile(replier), false),
+                AccountMeta::new(self.profile(introducer), false),
+                AccountMeta::new(self.vesting(replier), false),
+                AccountMeta::new_readonly(self.stake(replier), false),
+                AccountMeta::new_readonly(self.config(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        )
+    }
+}
+
+// Error raised by instruction 0 of a failed transaction
+pub fn instruction_error(result: Result<(), BanksClientError>) -> InstructionError {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(0, error) => error,
+        error => panic!("unexpected transaction error {error:?}"),
+    }
+}
+
+pub fn custom_error(error: IntroError) -> InstructionError {
+    InstructionError::Custom(error as u32)
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::client::reply_tree::{verify_reply, ReplyTreeBuilder};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::merkle;
+use solana_hello_world::pda;
+use solana_hello_world::state::ReplyTree;
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+fn reply_tree(env: &TestEnv, intro: &Pubkey) -> Pubkey {
+    find_pda(&pda::reply_tree_seeds(intro), &env.program_id)
+}
+
+fn create_reply_tree_ix(env: &TestEnv, introducer: &Pubkey, intro: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &[14],
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new_readonly(*intro, false),
+            AccountMeta::new(reply_tree(env, intro), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+fn add_compressed_reply_ix(env: &TestEnv, replier: &Pubkey, intro: &Pubkey, reply: &str) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(15, &reply.to_string()),
+        vec![
+            AccountMeta::new(*replier, true),
+            AccountMeta::new_readonly(*intro, false),
+            AccountMeta::new(env.reply_counter(intro), false),
+            AccountMeta::new(reply_tree(env, intro), false),
+        ],
+    )
+}
+
+async fn load_tree(env: &mut TestEnv, intro: &Pubkey) -> ReplyTree {
+    let data = env.account_data(&reply_tree(env, intro)).await;
+    ReplyTree::try_from_slice(&data).unwrap()
+}
+
+#[tokio::test]
+async fn compressed_replies_match_off_chain_tree() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+
+    let instruction = create_reply_tree_ix(&env, &introducer.pubkey(), &intro);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+    let tree = load_tree(&mut env, &intro).await;
+    assert_eq!(tree.leaf_count, 0);
+    assert_eq!(tree.root, merkle::empty_root());
+
+    // An account reply takes reply number 1, compressed replies share the numbering
+    let first_replier = env.funded_wallet().await;
+    let instruction = env.add_reply_ix(&first_replier.pubkey(), &intro, &introducer.pubkey(), 0, "Account reply");
+    env.send(&[instruction], &[&first_replier]).await.unwrap();
+
+    let mut builder = ReplyTreeBuilder::new(intro);
+    let mut replies = Vec::new();
+    for index in 0..5u64 {
+        let replier = env.funded_wallet().await;
+        let reply = format!("Compressed reply {index}");
+        let instruction = add_compressed_reply_ix(&env, &replier.pubkey(), &intro, &reply);
+        env.send(&[instruction], &[&replier]).await.unwrap();
+
+        let count = index + 2;
+        assert_eq!(builder.push_reply(&replier.pubkey(), &reply, count), Some(index));
+        replies.push((replier.pubkey(), reply, count));
+    }
+
+    let tree = load_tree(&mut env, &intro).await;
+    assert_eq!(tree.leaf_count, 5);
+    assert_eq!(tree.root, builder.root());
+
+    for (index, (replier, reply, count)) in replies.iter().enumerate() {
+        let proof = builder.proof(index as u64).unwrap();
+        assert!(verify_reply(&tree.root, &intro, replier, reply, *count, index as u64, &proof));
+    }
+}
+
+#[tokio::test]
+async fn only_the_introducer_creates_the_tree() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let other: Keypair = env.funded_wallet().await;
+
+    let instruction = create_reply_tree_ix(&env, &other.pubkey(), &intro);
+    let result = env.send(&[instruction], &[&other]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::Unauthorized));
+}
+
+#[tokio::test]
+async fn tree_must_belong_to_the_intro() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let other_intro = env.add_intro(&introducer, 1).await;
+
+    for intro in [intro, other_intro] {
+        let instruction = create_reply_tree_ix(&env, &introducer.pubkey(), &intro);
+        env.send(&[instruction], &[&introducer]).await.unwrap();
+    }
+
+    // Appending to another intro's tree is rejected
+    let replier = env.funded_wallet().await;
+    let mut instruction = add_compressed_reply_ix(&env, &replier.pubkey(), &intro, "Hi");
+    instruction.accounts[3].pubkey = reply_tree(&env, &other_intro);
+    let result = env.send(&[instruction], &[&replier]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::InvalidPDA));
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{find_pda, TestEnv};
+use solana_hello_world::pda;
+use solana_hello_world::state::VestingAccount;
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+
+fn follow_ix(env: &TestEnv, follower: &Pubkey, followee: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &[8],
+        vec![
+            AccountMeta::new(*follower, true),
+            AccountMeta::new_readonly(*followee, false),
+            AccountMeta::new(find_pda(&pda::follow_seeds(follower, followee), &env.program_id), false),
+            AccountMeta::new(env.profile(follower), false),
+            AccountMeta::new(env.profile(followee), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(env.vesting(follower), false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn first_follow_reward_vests() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let follower = env.funded_wallet().await;
+    env.create_profile(&follower).await;
+
+    for _ in 0..2 {
+        let followee = env.funded_wallet().await;
+        env.create_profile(&followee).await;
+        let instruction = follow_ix(&env, &follower.pubkey(), &followee.pubkey());
+        env.send(&[instruction], &[&follower]).await.unwrap();
+    }
+
+    // Only the first follow is rewarded, and nothing is minted until it vests
+    let vesting = VestingAccount::try_from_slice(&env.account_data(&env.vesting(&follower.pubkey())).await).unwrap();
+    assert_eq!(vesting.locked(), Ok(LAMPORTS_PER_SOL));
+    assert_eq!(env.token_balance(&env.ata(&follower.pubkey())).await, None);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::{Config, Proposal, ProposalAction};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+
+fn proposal(env: &TestEnv, index: u64) -> Pubkey {
+    find_pda(&pda::proposal_seeds(&index.to_be_bytes()), &env.program_id)
+}
+
+async fn load_config(env: &mut TestEnv) -> Config {
+    let data = env.account_data(&env.config()).await;
+    Config::deserialize(&mut &data[..]).unwrap()
+}
+
+#[tokio::test]
+async fn create_rejects_out_of_bounds_rewards() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let proposer = env.funded_wallet().await;
+    env.set_stake(&proposer.pubkey(), Proposal::PROPOSAL_THRESHOLD).await;
+
+    let actions = [
+        ProposalAction::SetIntroReward { amount: Proposal::MAX_INTRO_REWARD + 1 },
+        ProposalAction::SetReplyReward { amount: Proposal::MAX_REPLY_REWARD + 1 },
+    ];
+    for action in actions {
+        let instruction = Instruction::new_with_bytes(
+            env.program_id,
+            &instruction_data(31, &action),
+            vec![
+                AccountMeta::new(proposer.pubkey(), true),
+                AccountMeta::new_readonly(env.stake(&proposer.pubkey()), false),
+                AccountMeta::new(env.config(), false),
+                AccountMeta::new(proposal(&env, 0), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        );
+        let result = env.send(&[instruction], &[&proposer]).await;
+        assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ProposalOutOfBounds), "{action:?} was accepted");
+    }
+}
+
+#[tokio::test]
+async fn execute_rejects_out_of_bounds_rewards() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+
+    // A passed proposal whose action is outside the bounds, as if they were lowered while it was open
+    let (proposal_key, bump) = Pubkey::find_program_address(&pda::proposal_seeds(&0u64.to_be_bytes()), &env.program_id);
+    let proposal_data = Proposal {
+        discriminator: Proposal::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        index: 0,
+        proposer: Pubkey::new_unique(),
+        action: ProposalAction::SetIntroReward { amount: u64::MAX },
+        end_slot: 0,
+        yes_votes: Proposal::QUORUM,
+        no_votes: 0,
+        executed: false,
+        bump
+    };
+    env.set_program_account(&proposal_key, &proposal_data, Proposal::SIZE).await;
+
+    let instruction = Instruction::new_with_bytes(
+        env.program_id,
+        &[33],
+        vec![AccountMeta::new(env.config(), false), AccountMeta::new(proposal_key, false)],
+    );
+    let result = env.send(&[instruction], &[]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ProposalOutOfBounds));
+    assert_eq!(load_config(&mut env).await.intro_reward, None);
+}
+
+#[tokio::test]
+async fn update_intro_uses_governed_message_limit() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    env.add_intro(&introducer, 0).await;
+
+    let mut config = load_config(&mut env).await;
+    config.max_message_bytes = Some(100);
+    env.set_program_account(&env.config(), &config, Config::SIZE).await;
+
+    let instruction = Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(1, &(0u64, "Alice".to_string(), "a".repeat(101))),
+        vec![
+            AccountMeta::new(introducer.pubkey(), true),
+            AccountMeta::new(env.intro(&introducer.pubkey(), 0), false),
+            AccountMeta::new_readonly(env.stake(&introducer.pubkey()), false),
+            AccountMeta::new_readonly(env.config(), false),
+        ],
+    );
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ContentTooLong));
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::Config;
+use solana_program_test::tokio;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+#[tokio::test]
+async fn upgrade_authority_becomes_admin() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+
+    let account = env.context.banks_client.get_account(env.config()).await.unwrap().unwrap();
+    let config = Config::deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(config.admin, env.admin.pubkey());
+
+    let (_, stake_vault_bump) = Pubkey::find_program_address(&pda::stake_vault_seeds(), &env.program_id);
+    assert_eq!(config.stake_vault_bump, stake_vault_bump);
+}
+
+#[tokio::test]
+async fn other_wallets_cannot_initialize() {
+    let mut env = common::start().await;
+    let attacker = env.funded_wallet().await;
+
+    let instruction = env.initialize_mint_ix(&attacker.pubkey());
+    let result = env.send(&[instruction], &[&attacker]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::Unauthorized));
+}
+
+#[tokio::test]
+async fn program_data_must_belong_to_program() {
+    let mut env = common::start().await;
+    let admin = env.admin.insecure_clone();
+
+    let mut instruction = env.initialize_mint_ix(&admin.pubkey());
+    instruction.accounts[7].pubkey = common::program_data_address(&Pubkey::new_unique());
+    let result = env.send(&[instruction], &[&admin]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::IncorrectAccountError));
+
+    // Missing ProgramData account
+    let mut instruction = env.initialize_mint_ix(&admin.pubkey());
+    instruction.accounts.pop();
+    let result = env.send(&[instruction], &[&admin]).await;
+    assert_eq!(common::instruction_error(result), InstructionError::NotEnoughAccountKeys);
+}
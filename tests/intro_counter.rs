@@ -0,0 +1,26 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::{IntroCounter, IntroReplyCounter};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn add_intro_rejects_other_account_at_intro_counter() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    env.add_intro(&introducer, 0).await;
+
+    // Same layout as an intro counter, but tagged as a reply counter
+    let counter_key = common::find_pda(&pda::intro_counter_seeds(&introducer.pubkey()), &env.program_id);
+    let counter = IntroCounter::try_from_slice(&env.account_data(&counter_key).await).unwrap();
+    let mislabeled = IntroCounter { discriminator: IntroReplyCounter::DISCRIMINATOR.to_string(), ..counter };
+    env.set_program_account(&counter_key, &mislabeled, IntroCounter::SIZE).await;
+
+    let instruction = env.add_intro_ix(&introducer.pubkey(), 1, "Alice", "Hello again");
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::UninitializedAccount));
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::state::Config;
+use solana_program_test::tokio;
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Signer,
+};
+use spl_token::state::Mint;
+
+const MAX_SUPPLY: u64 = 1_000 * LAMPORTS_PER_SOL;
+
+fn update_config_ix(env: &TestEnv, referral_reward: u64, max_supply: u64) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(26, &(referral_reward, max_supply)),
+        vec![
+            AccountMeta::new_readonly(env.admin.pubkey(), true),
+            AccountMeta::new(env.config(), false),
+        ],
+    )
+}
+
+// Overwrite the mint supply, standing in for tokens minted before the program kept count
+async fn set_mint_supply(env: &mut TestEnv, supply: u64) {
+    let mint_key = env.token_mint();
+    let account = env.context.banks_client.get_account(mint_key).await.unwrap().unwrap();
+    let mut mint = Mint::unpack(&account.data).unwrap();
+    mint.supply = supply;
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(mint, &mut data).unwrap();
+    let mut account = AccountSharedData::from(account);
+    account.set_data_from_slice(&data);
+    env.context.set_account(&mint_key, &account);
+}
+
+#[tokio::test]
+async fn max_supply_counts_the_whole_mint_supply() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let admin = env.admin.insecure_clone();
+    let wallet = Pubkey::new_unique();
+
+    let instruction = update_config_ix(&env, 0, MAX_SUPPLY);
+    env.send(&[instruction], &[&admin]).await.unwrap();
+
+    // Supply the counter never saw still takes up room under the cap
+    set_mint_supply(&mut env, MAX_SUPPLY).await;
+    let instruction = env.airdrop_ix(&wallet, 1);
+    let result = env.send(&[instruction], &[&admin]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::MaxSupplyExceeded));
+
+    set_mint_supply(&mut env, MAX_SUPPLY - 1).await;
+    let instruction = env.airdrop_ix(&wallet, 1);
+    env.send(&[instruction], &[&admin]).await.unwrap();
+
+    let data = env.account_data(&env.config()).await;
+    let config = Config::deserialize(&mut &data[..]).unwrap();
+    assert_eq!(config.total_minted, 1);
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::pda;
+use solana_hello_world::state::{IntroAccountState, IntroReplyCounter, LegacyIntroAccountState, LegacyIntroReplyCounter};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+
+fn migrate_intro_ix(env: &TestEnv, introducer: &Pubkey) -> Instruction {
+    let intro = env.intro(introducer, 0);
+    Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(10, &()),
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new(find_pda(&pda::legacy_intro_seeds(introducer), &env.program_id), false),
+            AccountMeta::new(find_pda(&pda::legacy_reply_counter_seeds(introducer), &env.program_id), false),
+            AccountMeta::new(find_pda(&pda::intro_counter_seeds(introducer), &env.program_id), false),
+            AccountMeta::new(intro, false),
+            AccountMeta::new(env.reply_counter(&intro), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn migrate_max_size_legacy_intro() {
+    let mut env = common::start().await;
+    let introducer = env.funded_wallet().await;
+    let program_id = env.program_id;
+
+    // Fill the whole 1,000 byte legacy account, leaving no room for the fields intros gained since
+    let legacy_intro = LegacyIntroAccountState {
+        discriminator: IntroAccountState::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        introducer: introducer.pubkey(),
+        name: "Alice".to_string(),
+        message: "a".repeat(945),
+    };
+    let legacy_intro_key = find_pda(&pda::legacy_intro_seeds(&introducer.pubkey()), &program_id);
+    env.set_program_account(&legacy_intro_key, &legacy_intro, 1000).await;
+    let legacy_counter = LegacyIntroReplyCounter {
+        discriminator: IntroReplyCounter::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        counter: 3,
+    };
+    let legacy_counter_key = find_pda(&pda::legacy_reply_counter_seeds(&introducer.pubkey()), &program_id);
+    env.set_program_account(&legacy_counter_key, &legacy_counter, IntroReplyCounter::SIZE).await;
+
+    let instruction = migrate_intro_ix(&env, &introducer.pubkey());
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+
+    let intro_key = env.intro(&introducer.pubkey(), 0);
+    let intro = IntroAccountState::deserialize(&mut &env.account_data(&intro_key).await[..]).unwrap();
+    assert_eq!(intro.introducer, introducer.pubkey());
+    assert_eq!(intro.name, "Alice");
+    assert_eq!(intro.message, "a".repeat(945));
+    assert_eq!(intro.index, 0);
+
+    let counter = IntroReplyCounter::deserialize(&mut &env.account_data(&env.reply_counter(&intro_key)).await[..]).unwrap();
+    assert_eq!(counter.counter, 3);
+}
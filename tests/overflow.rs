@@ -0,0 +1,101 @@
+// Counters seeded near u64::MAX must fail with ArithmeticOverflow rather than wrap or panic
+
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::{IntroCounter, IntroReplyCounter, StakeAccount, UserProfile};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+#[tokio::test]
+async fn reply_counter_at_max() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+
+    let counter_key = env.reply_counter(&intro);
+    let mut counter = IntroReplyCounter::try_from_slice(&env.account_data(&counter_key).await).unwrap();
+    counter.counter = u64::MAX;
+    env.set_program_account(&counter_key, &counter, IntroReplyCounter::SIZE).await;
+
+    let replier = env.funded_wallet().await;
+    let instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), u64::MAX, "Welcome!");
+    let result = env.send(&[instruction], &[&replier]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ArithmeticOverflow));
+}
+
+#[tokio::test]
+async fn intro_counter_at_max() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    env.add_intro(&introducer, 0).await;
+
+    let counter_key = common::find_pda(&pda::intro_counter_seeds(&introducer.pubkey()), &env.program_id);
+    let mut counter = IntroCounter::try_from_slice(&env.account_data(&counter_key).await).unwrap();
+    counter.count = u64::MAX;
+    env.set_program_account(&counter_key, &counter, IntroCounter::SIZE).await;
+
+    let instruction = env.add_intro_ix(&introducer.pubkey(), u64::MAX, "Alice", "Hello again");
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ArithmeticOverflow));
+}
+
+#[tokio::test]
+async fn profile_counter_at_max() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let replier = env.funded_wallet().await;
+    env.create_profile(&replier).await;
+
+    let profile_key = env.profile(&replier.pubkey());
+    let data = env.account_data(&profile_key).await;
+    let len = data.len();
+    let mut profile = UserProfile::deserialize(&mut &data[..]).unwrap();
+    profile.replies_written = u64::MAX;
+    env.set_program_account(&profile_key, &profile, len).await;
+
+    let instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), 0, "Welcome!");
+    let result = env.send(&[instruction], &[&replier]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ArithmeticOverflow));
+}
+
+#[tokio::test]
+async fn unbonding_amount_at_max() {
+    let mut env = common::start().await;
+    let wallet = env.funded_wallet().await;
+
+    let (stake_key, bump) = Pubkey::find_program_address(&pda::stake_seeds(&wallet.pubkey()), &env.program_id);
+    let stake = StakeAccount {
+        discriminator: StakeAccount::DISCRIMINATOR.to_string(),
+        is_initialized: true,
+        wallet: wallet.pubkey(),
+        amount: 1,
+        unbonding_amount: u64::MAX,
+        unbonding_end_slot: 0,
+        bump
+    };
+    env.set_program_account(&stake_key, &stake, StakeAccount::SIZE).await;
+
+    let unstake = |amount: u64| Instruction::new_with_bytes(
+        env.program_id,
+        &common::instruction_data(20, &amount),
+        vec![AccountMeta::new(wallet.pubkey(), true), AccountMeta::new(stake_key, false)],
+    );
+    let (overflowing, excessive) = (unstake(1), unstake(2));
+
+    let result = env.send(&[overflowing], &[&wallet]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ArithmeticOverflow));
+
+    let result = env.send(&[excessive], &[&wallet]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::InsufficientStake));
+}
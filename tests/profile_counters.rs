@@ -0,0 +1,42 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_hello_world::error::IntroError;
+use solana_hello_world::state::UserProfile;
+use solana_program_test::tokio;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+#[tokio::test]
+async fn add_reply_updates_existing_profiles() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let replier = env.funded_wallet().await;
+    env.create_profile(&replier).await;
+
+    let instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), 0, "Welcome!");
+    env.send(&[instruction], &[&replier]).await.unwrap();
+
+    let account = env.context.banks_client.get_account(env.profile(&replier.pubkey())).await.unwrap().unwrap();
+    let profile = UserProfile::deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(profile.replies_written, 1);
+}
+
+#[tokio::test]
+async fn add_reply_rejects_substituted_profile() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let replier = env.funded_wallet().await;
+    env.create_profile(&replier).await;
+
+    // An empty account in place of the replier's profile would skip the counter update
+    let mut instruction = env.add_reply_ix(&replier.pubkey(), &intro, &introducer.pubkey(), 0, "Welcome!");
+    instruction.accounts[5].pubkey = Pubkey::new_unique();
+    let result = env.send(&[instruction], &[&replier]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::InvalidPDA));
+}
@@ -0,0 +1,155 @@
+// Every instruction that invokes another program must reject a fake program passed in its place
+
+mod common;
+
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::ProposalAction;
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program, sysvar,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+struct Case {
+    name: &'static str,
+    instruction: Instruction,
+    // Indexes of the program accounts in `instruction.accounts`
+    program_slots: Vec<usize>,
+}
+
+fn case(env: &TestEnv, name: &'static str, data: Vec<u8>, signer: &Pubkey, accounts: Vec<Pubkey>, program_slots: Vec<usize>) -> Case {
+    let mut metas = vec![AccountMeta::new(*signer, true)];
+    metas.extend(accounts.into_iter().map(|key| AccountMeta::new(key, false)));
+    Case { name, instruction: Instruction::new_with_bytes(env.program_id, &data, metas), program_slots }
+}
+
+#[tokio::test]
+async fn fake_programs_are_rejected() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let admin = env.admin.insecure_clone();
+
+    let introducer = env.funded_wallet().await;
+    let intro = env.add_intro(&introducer, 0).await;
+    let user = env.funded_wallet().await;
+    let reply_instruction = env.add_reply_ix(&user.pubkey(), &intro, &introducer.pubkey(), 0, "Welcome!");
+    env.send(&[reply_instruction], &[&user]).await.unwrap();
+    let reply = env.reply(&intro, 0);
+    env.create_profile(&user).await;
+    env.create_profile(&introducer).await;
+
+    let user_key = user.pubkey();
+    let program_id = env.program_id;
+    let system = system_program::id();
+    let token = spl_token::id();
+    let ata_program = spl_associated_token_account::id();
+    let config = env.config();
+    let mint = env.token_mint();
+    let token_auth = env.token_auth();
+    let user_ata = get_associated_token_address(&user_key, &mint);
+    let vault_auth = find_pda(&pda::stake_vault_seeds(), &program_id);
+    let vault_ata = get_associated_token_address(&vault_auth, &mint);
+    let bounty = find_pda(&pda::bounty_seeds(&intro), &program_id);
+    let escrow_ata = get_associated_token_address(&bounty, &mint);
+    let proposal = find_pda(&pda::proposal_seeds(&0u64.to_be_bytes()), &program_id);
+    let user_intro = env.intro(&user_key, 0);
+    let introducer_ata = get_associated_token_address(&introducer.pubkey(), &mint);
+
+    let mut cases = vec![
+        case(&env, "AddIntro", instruction_data(0, &("Bob".to_string(), "Hi".to_string(), None::<Pubkey>, None::<Pubkey>)), &user_key,
+            vec![find_pda(&pda::intro_counter_seeds(&user_key), &program_id), user_intro, env.reply_counter(&user_intro), env.vesting(&user_key), env.stake(&user_key), config, system],
+            vec![7]),
+        case(&env, "AddReply", instruction_data(2, &"Hi".to_string()), &user_key,
+            vec![intro, env.reply_counter(&intro), env.reply(&intro, 1), find_pda(&pda::bond_seeds(&env.reply(&intro, 1)), &program_id),
+                env.profile(&user_key), env.profile(&introducer.pubkey()), env.vesting(&user_key), env.stake(&user_key), config, system],
+            vec![10]),
+        case(&env, "InitializeMint", vec![3], &admin.pubkey(),
+            vec![mint, token_auth, config, system, token, sysvar::rent::id(), common::program_data_address(&program_id)],
+            vec![4, 5]),
+        case(&env, "CreateProfile", instruction_data(6, &("Bob".to_string(), String::new(), String::new())), &user_key,
+            vec![env.profile(&user_key), system],
+            vec![2]),
+        case(&env, "Follow", vec![8], &user_key,
+            vec![introducer.pubkey(), find_pda(&pda::follow_seeds(&user_key, &introducer.pubkey()), &program_id), env.profile(&user_key), env.profile(&introducer.pubkey()), system,
+                env.vesting(&user_key)],
+            vec![5]),
+        case(&env, "MigrateIntro", vec![10], &user_key,
+            vec![find_pda(&pda::legacy_intro_seeds(&user_key), &program_id), find_pda(&pda::legacy_reply_counter_seeds(&user_key), &program_id),
+                find_pda(&pda::intro_counter_seeds(&user_key), &program_id), user_intro, env.reply_counter(&user_intro), system],
+            vec![6]),
+        case(&env, "CreateCommunity", instruction_data(11, &("rust".to_string(), String::new(), Vec::<Pubkey>::new(), 100u16)), &user_key,
+            vec![find_pda(&pda::community_seeds("rust"), &program_id), system],
+            vec![2]),
+        case(&env, "CreateReplyTree", vec![14], &introducer.pubkey(),
+            vec![intro, find_pda(&pda::reply_tree_seeds(&intro), &program_id), system],
+            vec![3]),
+        case(&env, "AddReplies", instruction_data(16, &vec!["Hi".to_string()]), &admin.pubkey(),
+            vec![user_key, intro, env.reply_counter(&intro), env.vesting(&user_key), system, config],
+            vec![5]),
+        case(&env, "ClaimVested", vec![17], &user_key,
+            vec![env.vesting(&user_key), mint, token_auth, user_ata, system, token, ata_program, config],
+            vec![5, 6, 7]),
+        case(&env, "Stake", instruction_data(19, &1u64), &user_key,
+            vec![env.stake(&user_key), user_ata, vault_auth, vault_ata, mint, system, token, ata_program, config],
+            vec![6, 7, 8]),
+        case(&env, "WithdrawStake", vec![21], &user_key,
+            vec![env.stake(&user_key), user_ata, vault_auth, vault_ata, mint, system, token, ata_program, config],
+            vec![6, 7, 8]),
+        case(&env, "BoostIntro", instruction_data(22, &1u64), &user_key,
+            vec![intro, user_ata, mint, token, config],
+            vec![4]),
+        case(&env, "CreateBounty", instruction_data(23, &(1u64, u64::MAX)), &introducer.pubkey(),
+            vec![intro, bounty, introducer_ata, escrow_ata, mint, system, token, ata_program, config],
+            vec![6, 7, 8]),
+        case(&env, "AwardBounty", vec![24], &introducer.pubkey(),
+            vec![intro, bounty, reply, user_key, user_ata, introducer_ata, escrow_ata, mint, system, token, ata_program, config],
+            vec![9, 10, 11]),
+        case(&env, "CancelBounty", vec![25], &introducer.pubkey(),
+            vec![intro, bounty, introducer_ata, escrow_ata, mint, token, config],
+            vec![6]),
+        case(&env, "Airdrop", instruction_data(27, &vec![1u64]), &admin.pubkey(),
+            vec![config, mint, token_auth, system, token, ata_program, user_key, user_ata],
+            vec![4, 5, 6]),
+        case(&env, "SetFreezeAuthority", instruction_data(28, &None::<Pubkey>), &admin.pubkey(),
+            vec![config, mint, token_auth, token],
+            vec![4]),
+        case(&env, "TransferMintAuthority", instruction_data(29, &Pubkey::new_unique()), &admin.pubkey(),
+            vec![config, mint, token_auth, token],
+            vec![4]),
+        case(&env, "RevokeMintAuthority", vec![30], &admin.pubkey(),
+            vec![config, mint, token_auth, token],
+            vec![4]),
+        case(&env, "CreateProposal", instruction_data(31, &ProposalAction::SetIntroReward { amount: 1 }), &user_key,
+            vec![env.stake(&user_key), config, proposal, system],
+            vec![4]),
+        case(&env, "CastVote", instruction_data(32, &true), &user_key,
+            vec![env.stake(&user_key), proposal, find_pda(&pda::vote_seeds(&proposal, &user_key), &program_id), system],
+            vec![4]),
+    ];
+    // AddReplies is also signed by the reply author
+    cases.iter_mut().find(|case| case.name == "AddReplies").unwrap().instruction.accounts[1].is_signer = true;
+
+    let signers: Vec<Keypair> = vec![user.insecure_clone(), introducer.insecure_clone(), admin.insecure_clone()];
+    for Case { name, instruction, program_slots } in cases {
+        let case_signers: Vec<&Keypair> = signers
+            .iter()
+            .filter(|signer| instruction.accounts.iter().any(|meta| meta.is_signer && meta.pubkey == signer.pubkey()))
+            .collect();
+        for slot in program_slots {
+            let mut substituted = instruction.clone();
+            let fake_program = Pubkey::new_unique();
+            substituted.accounts[slot] = AccountMeta::new_readonly(fake_program, false);
+            let result = env.send(&[substituted], &case_signers).await;
+            assert_eq!(
+                common::instruction_error(result),
+                common::custom_error(IntroError::IncorrectProgramId),
+                "{name} accepted a fake program in account {slot}"
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{find_pda, instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::pda;
+use solana_hello_world::state::{Config, StakeAccount, VestingAccount};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+// AddIntro as the first intro of `referee`, referred by `referrer`
+fn referred_intro_ix(env: &TestEnv, referee: &Pubkey, referrer: &Pubkey) -> Instruction {
+    let mut instruction = env.add_intro_ix(referee, 0, "Bob", "Hi");
+    instruction.data = instruction_data(0, &("Bob".to_string(), "Hi".to_string(), None::<Pubkey>, Some(*referrer)));
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(*referrer, false),
+        AccountMeta::new(find_pda(&pda::referral_seeds(referee), &env.program_id), false),
+        AccountMeta::new(env.vesting(referrer), false),
+    ]);
+    instruction
+}
+
+#[tokio::test]
+async fn referral_reward_vests_to_the_referrer() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let referrer = env.funded_wallet().await;
+    let referee = env.funded_wallet().await;
+    env.set_stake(&referee.pubkey(), StakeAccount::TIER_THRESHOLDS[0]).await;
+
+    let instruction = referred_intro_ix(&env, &referee.pubkey(), &referrer.pubkey());
+    env.send(&[instruction], &[&referee]).await.unwrap();
+
+    let vesting = VestingAccount::try_from_slice(&env.account_data(&env.vesting(&referrer.pubkey())).await).unwrap();
+    assert_eq!(vesting.locked(), Ok(Config::DEFAULT_REFERRAL_REWARD));
+    assert_eq!(vesting.claimed, 0);
+}
+
+#[tokio::test]
+async fn unstaked_referee_is_rejected() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let referrer = env.funded_wallet().await;
+    let referee = env.funded_wallet().await;
+    env.set_stake(&referee.pubkey(), StakeAccount::TIER_THRESHOLDS[0] - 1).await;
+
+    let instruction = referred_intro_ix(&env, &referee.pubkey(), &referrer.pubkey());
+    let result = env.send(&[instruction], &[&referee]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::InsufficientStake));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{instruction_data, TestEnv};
+use solana_hello_world::error::IntroError;
+use solana_hello_world::state::StakeAccount;
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+fn update_intro_ix(env: &TestEnv, introducer: &Pubkey, index: u64, message: &str) -> Instruction {
+    Instruction::new_with_bytes(
+        env.program_id,
+        &instruction_data(1, &(index, "Alice".to_string(), message.to_string())),
+        vec![
+            AccountMeta::new(*introducer, true),
+            AccountMeta::new(env.intro(introducer, index), false),
+            AccountMeta::new_readonly(env.stake(introducer), false),
+            AccountMeta::new_readonly(env.config(), false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn update_uses_stake_tier_limits() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    // 1,500 bytes in 750 characters, over the base byte limit but within the tier 1 limits
+    let long_message = "é".repeat(750);
+
+    // Tier 0 keeps the base message limit
+    env.add_intro(&introducer, 0).await;
+    let instruction = update_intro_ix(&env, &introducer.pubkey(), 0, &long_message);
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::ContentTooLong));
+
+    // An intro created at tier 1 can be updated up to the tier 1 limit
+    env.set_stake(&introducer.pubkey(), StakeAccount::TIER_THRESHOLDS[0]).await;
+    env.add_intro(&introducer, 1).await;
+    let instruction = update_intro_ix(&env, &introducer.pubkey(), 1, &long_message);
+    env.send(&[instruction], &[&introducer]).await.unwrap();
+}
+
+#[tokio::test]
+async fn update_must_fit_the_existing_account() {
+    let mut env = common::start().await;
+    env.initialize_mint().await;
+    let introducer = env.funded_wallet().await;
+    env.add_intro(&introducer, 0).await;
+
+    // Staking after creation raises the limit, but the tier 0 account has no room for the extra bytes
+    env.set_stake(&introducer.pubkey(), StakeAccount::TIER_THRESHOLDS[0]).await;
+    let instruction = update_intro_ix(&env, &introducer.pubkey(), 0, &"é".repeat(750));
+    let result = env.send(&[instruction], &[&introducer]).await;
+    assert_eq!(common::instruction_error(result), common::custom_error(IntroError::InvalidDataLength));
+}